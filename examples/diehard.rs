@@ -4,7 +4,7 @@ use genetic_planner::genetic_planner::{State, Action, Plan, PlannerConfiguration
 
 
 extern crate rand;
-use rand::Rng;
+use rand::{Rng, XorShiftRng};
 
 use std::fmt;
 
@@ -102,16 +102,14 @@ fn fill_b_with_a(c: Cans) -> Option<Cans> {
 
 
 impl gp::State for Cans {
+    type Score = i32;
+
     fn is_goal(&self) -> bool {
         self.can_a == TARGET
     }
 
     fn get_heuristic(&self) -> i32 {
-        (if self.can_a > TARGET {
-            self.can_a - TARGET
-        } else {
-            TARGET - self.can_a
-        }) as i32
+        self.can_a.abs_diff(TARGET) as i32
     }
 
     fn get_initial_state() -> Cans {
@@ -121,8 +119,8 @@ impl gp::State for Cans {
         }
     }
 
-    fn get_random_action() -> gp::Action<Cans> {
-        let r: f32 = rand::thread_rng().gen_range(0f32, 1f32);
+    fn get_random_action_with_rng(rng: &mut XorShiftRng) -> gp::Action<Cans> {
+        let r: f32 = rng.gen_range(0f32, 1f32);
         if r < (1f32 / 6f32) {
             Action {
                 action: fill_a,
@@ -160,24 +158,20 @@ impl gp::State for Cans {
 /// The Dia Hard Riddle:
 /// You are given a 5 gallon jug and a 3 gallon jug in front of a fountain.
 /// Measure out exactly 4 gallons of water.
-
 fn main() {
     println!("Running...");
-    let pc = PlannerConfiguration {
-        max_actions: 20,
-        population_size: 100,
-        tournmant_size: 40,
-        elitism_size: 3,
-        uniform_rate: 0.5,
-        mutation_rate: 0.7,
-        threadpool_size: 32,
-    };
+    let pc = PlannerConfiguration::builder()
+        .initial_actions(20)
+        .max_actions(20)
+        .tournmant_size(40)
+        .elitism_size(3)
+        .mutation_rate(0.7)
+        .build()
+        .expect("invalid configuration");
     let mut state: Cans = Cans::get_initial_state();
-    let n: Plan<Cans> = gp::find_best_after_iterations(pc, 500);
-    let mut j = 0;
-    for i in n.actions {
+    let n: Plan<Cans> = gp::find_best_after_iterations(pc, 500).expect("empty population");
+    for (j, i) in n.actions.into_iter().enumerate() {
         println!("({}):{}", j, i.name);
-        j += 1;
         let op_state = (i.action)(state);
         println!("{}", op_state.clone().unwrap());
         state = op_state.unwrap();
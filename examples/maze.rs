@@ -1,12 +1,17 @@
 extern crate genetic_planner;
 use genetic_planner::genetic_planner as gp;
 use genetic_planner::genetic_planner::{State, Action, Plan, PlannerConfiguration};
+use genetic_planner::genetic::Population;
+use genetic_planner::adaptation::{DiversityBoost, ExponentialDecay};
+use genetic_planner::restart::Restart;
+use genetic_planner::memetic::{HillClimbing, Memetic};
 
 
 extern crate rand;
-use rand::Rng;
+use rand::{Rng, XorShiftRng};
 
 use std::fmt;
+use std::sync::Arc;
 
 const MAZE_SIZE: usize = 10;
 
@@ -29,20 +34,19 @@ impl fmt::Display for Maze {
         let maze = self.maze;
         for r in maze.iter() {
             for c in r.iter() {
-                txt = txt +
-                      if self.bot_position == position {
+                txt += if self.bot_position == position {
                     "<B>"
                 } else {
-                    match c {
-                        &Tile::Wall => "[W]",
-                        &Tile::Empty => "[ ]",
-                        &Tile::Finish => "[F]",
+                    match *c {
+                        Tile::Wall => "[W]",
+                        Tile::Empty => "[ ]",
+                        Tile::Finish => "[F]",
                     }
                 };
 
                 position = (position.0, position.1 + 1);
             }
-            txt = txt + "\n";
+            txt += "\n";
             position = (position.0 + 1, 0);
         }
         write!(f, "{}", txt)
@@ -66,13 +70,10 @@ fn go_down(m: Maze) -> Option<Maze> {
 }
 
 fn go(m: Maze, d: (isize, isize)) -> Option<Maze> {
-    if m.bot_position.1 == 0 && d.1 == -1 {
-        None
-    } else if m.bot_position.1 == MAZE_SIZE - 1 && d.1 == 1 {
-        None
-    } else if m.bot_position.0 == 0 && d.0 == -1 {
-        None
-    } else if m.bot_position.0 == MAZE_SIZE - 1 && d.0 == 1 {
+    if (m.bot_position.1 == 0 && d.1 == -1) ||
+       (m.bot_position.1 == MAZE_SIZE - 1 && d.1 == 1) ||
+       (m.bot_position.0 == 0 && d.0 == -1) ||
+       (m.bot_position.0 == MAZE_SIZE - 1 && d.0 == 1) {
         None
     } else {
         let new_pos = sum(&m.bot_position, &d);
@@ -89,7 +90,7 @@ fn go(m: Maze, d: (isize, isize)) -> Option<Maze> {
 }
 
 fn sum(a: &(usize, usize), b: &(isize, isize)) -> (usize, usize) {
-    let mut c = a.clone();
+    let mut c = *a;
     match b.0 {
         1 => c = (c.0 + 1, c.1),
         -1 => c = (c.0 - 1, c.1),
@@ -104,6 +105,8 @@ fn sum(a: &(usize, usize), b: &(isize, isize)) -> (usize, usize) {
 }
 
 impl gp::State for Maze {
+    type Score = i32;
+
     fn is_goal(&self) -> bool {
         let (y, x) = self.bot_position;
         self.maze[y][x] == Tile::Finish
@@ -155,8 +158,8 @@ impl gp::State for Maze {
         }
     }
 
-    fn get_random_action() -> gp::Action<Maze> {
-        let r: u8 = rand::thread_rng().gen();
+    fn get_random_action_with_rng(rng: &mut XorShiftRng) -> gp::Action<Maze> {
+        let r: u8 = rng.gen();
         if r < 64 {
             Action {
                 action: go_up,
@@ -191,22 +194,29 @@ fn print(m: &Option<Maze>) {
 /// Find the path for a bot in a maze 
 fn main() {
     println!("Running...");
-    let pc = PlannerConfiguration {
-        max_actions: 40,
-        population_size: 200,
-        tournmant_size: 20,
-        elitism_size: 3,
-        uniform_rate: 0.5,
-        mutation_rate: 0.7,
-        threadpool_size: 16,
-    };
+    let pc = PlannerConfiguration::builder()
+        .exploratory()
+        .initial_actions(20)
+        .max_actions(60)
+        .tournmant_size(20)
+        .elitism_size(3)
+        .restart(Restart::Catastrophe { patience: 50 })
+        .build()
+        .expect("invalid configuration");
+    // start with an high mutation rate, which decays while the plans are
+    // different enough and grows again when they become too similar
+    let mut pop_configuration = gp::get_population_configuration(pc)
+        .expect("invalid configuration");
+    pop_configuration.mutation_adaptation = Some(Arc::new(DiversityBoost::new(0.3, 1.5, 0.7)
+        .otherwise(ExponentialDecay::new(0.98, 0.1))));
+    // a short hill climbing finishes the plans which are close to the goal
+    pop_configuration.memetic = Some(Memetic::lamarckian(HillClimbing, 0.1, 10));
     let mut state: Maze = Maze::get_initial_state();
-    let n: Plan<Maze> = gp::find_solution(pc);
+    let pop = Population::new(pop_configuration).expect("invalid configuration");
+    let n: Plan<Maze> = gp::find_solution_from_population(pop).expect("empty population");
     println!("[W] Wall [F] Finish <B> Bot\n");
-    let mut j = 0;
-    for i in n.actions {
+    for (j, i) in n.actions.into_iter().enumerate() {
         println!("({}):{}", j, i.name);
-        j += 1;
         let op_state = (i.action)(state);
         print(&op_state);
         state = op_state.unwrap();
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+/// Information about the last generation used to adapt the rates
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub struct Feedback {
+    /// Generation of the Population
+    pub generation: usize,
+    /// Fraction of the offsprings which are better than both their parents
+    pub success_rate: f64,
+    /// Fraction of different genomes in the Population,
+    /// estimated on a sample of the Individuals
+    pub diversity: f64,
+}
+
+/// Schedule of a rate of the configuration, called after each generation
+pub trait Adaptation: Send + Sync {
+    /// Return the rate used to create the next generation
+    fn adapt(&self, rate: f32, feedback: &Feedback) -> f32;
+}
+
+/// Move the rate linearly from from to to in generations generations,
+/// then the rate stays to
+#[derive(Debug,Clone)]
+pub struct LinearDecay {
+    /// Rate of the first generation
+    pub from: f32,
+    /// Rate after generations generations
+    pub to: f32,
+    /// Number of generations of the decay
+    pub generations: usize,
+}
+
+impl LinearDecay {
+    /// Create a new LinearDecay
+    pub fn new(from: f32, to: f32, generations: usize) -> LinearDecay {
+        LinearDecay {
+            from,
+            to,
+            generations,
+        }
+    }
+}
+
+impl Adaptation for LinearDecay {
+    fn adapt(&self, _: f32, feedback: &Feedback) -> f32 {
+        if feedback.generation >= self.generations {
+            return self.to;
+        }
+        let progress = feedback.generation as f32 / self.generations as f32;
+        self.from + (self.to - self.from) * progress
+    }
+}
+
+/// Multiply the rate by factor at each generation, without going below minimum
+#[derive(Debug,Clone)]
+pub struct ExponentialDecay {
+    /// Factor applied to the rate, usually lower than 1
+    pub factor: f32,
+    /// Lowest rate
+    pub minimum: f32,
+}
+
+impl ExponentialDecay {
+    /// Create a new ExponentialDecay
+    pub fn new(factor: f32, minimum: f32) -> ExponentialDecay {
+        ExponentialDecay { factor, minimum }
+    }
+}
+
+impl Adaptation for ExponentialDecay {
+    fn adapt(&self, rate: f32, _: &Feedback) -> f32 {
+        (rate * self.factor).max(self.minimum)
+    }
+}
+
+/// The 1/5th success rule: if more than 1/5 of the offsprings improve on their
+/// parents the rate is multiplied by factor, if less it is divided by factor
+#[derive(Debug,Clone)]
+pub struct OneFifthRule {
+    /// Factor applied to the rate, greater than 1
+    pub factor: f32,
+    /// Lowest rate
+    pub minimum: f32,
+    /// Highest rate
+    pub maximum: f32,
+}
+
+impl OneFifthRule {
+    /// Create a new OneFifthRule
+    pub fn new(factor: f32, minimum: f32, maximum: f32) -> OneFifthRule {
+        OneFifthRule {
+            factor,
+            minimum,
+            maximum,
+        }
+    }
+}
+
+impl Adaptation for OneFifthRule {
+    fn adapt(&self, rate: f32, feedback: &Feedback) -> f32 {
+        let rate = if feedback.success_rate > 0.2f64 {
+            rate * self.factor
+        } else if feedback.success_rate < 0.2f64 {
+            rate / self.factor
+        } else {
+            rate
+        };
+        rate.max(self.minimum).min(self.maximum)
+    }
+}
+
+/// Multiply the rate by factor when the diversity of the Population falls below
+/// threshold, otherwise the rate is left to an other Adaptation, if any
+#[derive(Clone)]
+pub struct DiversityBoost {
+    /// Diversity under which the rate is increased
+    pub threshold: f64,
+    /// Factor applied to the rate, greater than 1
+    pub factor: f32,
+    /// Highest rate
+    pub maximum: f32,
+    /// Adaptation used when the diversity is above threshold
+    pub otherwise: Option<Arc<dyn Adaptation>>,
+}
+
+impl DiversityBoost {
+    /// Create a new DiversityBoost which does not change the rate
+    /// while the diversity is above threshold
+    pub fn new(threshold: f64, factor: f32, maximum: f32) -> DiversityBoost {
+        DiversityBoost {
+            threshold,
+            factor,
+            maximum,
+            otherwise: None,
+        }
+    }
+
+    /// Return the DiversityBoost with the Adaptation used
+    /// when the diversity is above threshold
+    pub fn otherwise<A>(mut self, adaptation: A) -> DiversityBoost
+        where A: Adaptation + 'static
+    {
+        self.otherwise = Some(Arc::new(adaptation));
+        self
+    }
+}
+
+impl Adaptation for DiversityBoost {
+    fn adapt(&self, rate: f32, feedback: &Feedback) -> f32 {
+        if feedback.diversity < self.threshold {
+            (rate * self.factor).min(self.maximum.max(rate))
+        } else {
+            match self.otherwise {
+                Some(ref adaptation) => adaptation.adapt(rate, feedback),
+                None => rate,
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use adaptation::*;
+use genetic::*;
+use test_helpers::configuration_builder;
+
+#[allow(dead_code)]
+fn feedback(generation: usize, success_rate: f64, diversity: f64) -> Feedback {
+    Feedback {
+        generation,
+        success_rate,
+        diversity,
+    }
+}
+
+#[test]
+fn linear_decay() {
+    let a = LinearDecay::new(0.8f32, 0.2f32, 6);
+    assert!((a.adapt(0.5f32, &feedback(3, 0f64, 1f64)) - 0.5f32).abs() < 1e-6);
+    assert_eq!(a.adapt(0.5f32, &feedback(0, 0f64, 1f64)), 0.8f32);
+    assert_eq!(a.adapt(0.5f32, &feedback(10, 0f64, 1f64)), 0.2f32);
+}
+
+#[test]
+fn exponential_decay() {
+    let a = ExponentialDecay::new(0.5f32, 0.1f32);
+    assert_eq!(a.adapt(0.8f32, &feedback(1, 0f64, 1f64)), 0.4f32);
+    assert_eq!(a.adapt(0.15f32, &feedback(1, 0f64, 1f64)), 0.1f32);
+}
+
+#[test]
+fn one_fifth_rule() {
+    let a = OneFifthRule::new(2f32, 0.05f32, 0.5f32);
+    assert_eq!(a.adapt(0.2f32, &feedback(1, 0.5f64, 1f64)), 0.4f32);
+    assert_eq!(a.adapt(0.2f32, &feedback(1, 0.1f64, 1f64)), 0.1f32);
+    assert_eq!(a.adapt(0.2f32, &feedback(1, 0.2f64, 1f64)), 0.2f32);
+    assert_eq!(a.adapt(0.4f32, &feedback(1, 0.9f64, 1f64)), 0.5f32);
+}
+
+#[test]
+fn diversity_boost() {
+    let a = DiversityBoost::new(0.5f64, 2f32, 0.6f32)
+        .otherwise(ExponentialDecay::new(0.5f32, 0f32));
+    assert_eq!(a.adapt(0.2f32, &feedback(1, 0f64, 0.1f64)), 0.4f32);
+    assert_eq!(a.adapt(0.4f32, &feedback(1, 0f64, 0.1f64)), 0.6f32);
+    assert_eq!(a.adapt(0.4f32, &feedback(1, 0f64, 0.9f64)), 0.2f32);
+}
+
+#[test]
+fn population_rates() {
+    let c = configuration_builder(|i: &Individual<u8>| i.genes[0] as i32, 16, 4, 6)
+        .mutation_rate(0.8f32)
+        .mutation_adaptation(LinearDecay::new(0.8f32, 0.4f32, 4))
+        .crossover_adaptation(ExponentialDecay::new(0.5f32, 0.25f32))
+        .build()
+        .unwrap();
+    let mut p = Population::new(c).unwrap();
+    p = p.evolve();
+    assert!((p.configuration.mutation_rate - 0.7f32).abs() < 1e-6);
+    assert_eq!(p.configuration.uniform_rate, 0.25f32);
+    for _ in 0..4 {
+        p = p.evolve();
+    }
+    assert_eq!(p.configuration.mutation_rate, 0.4f32);
+    assert_eq!(p.configuration.uniform_rate, 0.25f32);
+}
@@ -0,0 +1,356 @@
+extern crate rand;
+use rand::Rand;
+
+use std::sync::Arc;
+use std::thread;
+
+use genetic::{Individual, PopulationConfiguration};
+use selection::{Selection, Tournament};
+use crossover::{Crossover, UniformCrossover};
+use mutation::{Mutation, RandomResetting};
+use cache::FitnessCache;
+use score::{Direction, Score};
+use replacement::Replacement;
+use adaptation::Adaptation;
+use niching::Niching;
+use restart::Restart;
+use memetic::Memetic;
+use observer::Observer;
+use error::Error;
+use generator::{GeneGenerator, RandomGenes};
+
+/// Get the number of threads used by default, one for each available CPU
+pub fn default_threadpool_size() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Builder of a PopulationConfiguration, only the fitness is required and the
+/// GeneGenerator if the genes do not implement Rand. The defaults are:
+///
+/// * generator: RandomGenes
+/// * population_size: 100
+/// * direction: Maximise
+/// * genenumber: 10, min_genenumber and max_genenumber: genenumber
+/// * crossover: UniformCrossover with uniform_rate 0.5
+/// * mutation: RandomResetting with mutation_rate 0.1, without local search
+/// * selection: Tournament of 4 Individuals
+/// * elitism_size: 1
+/// * replacement: Generational, without niching and duplicates elimination
+/// * no restart when the best score stops improving
+/// * no adaptations of the rates, no observers, no cache and no objectives
+/// * no batch_fitness, the Individuals are evaluated one by one
+/// * threadpool_size: the number of available CPUs
+/// * seed: None
+///
+/// The presets change some of the defaults, the setters called
+/// after a preset override its values
+#[derive(Clone)]
+pub struct PopulationConfigurationBuilder<T: 'static, S: 'static = i32> {
+    configuration: PopulationConfiguration<T, S>,
+    min_genenumber: Option<usize>,
+    max_genenumber: Option<usize>,
+}
+
+impl<T, S> PopulationConfigurationBuilder<T, S>
+    where T: Clone + Rand + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    /// Create a new PopulationConfigurationBuilder with the fitness and the defaults
+    pub fn new<F>(fitness: F) -> PopulationConfigurationBuilder<T, S>
+        where F: Fn(&Individual<T>) -> S + Send + Sync + 'static
+    {
+        PopulationConfigurationBuilder::with_generator(fitness, RandomGenes)
+    }
+}
+
+impl<T, S> PopulationConfigurationBuilder<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    /// Create a new PopulationConfigurationBuilder with the fitness, the generator
+    /// of the genes and the defaults
+    pub fn with_generator<F, G>(fitness: F, generator: G) -> PopulationConfigurationBuilder<T, S>
+        where F: Fn(&Individual<T>) -> S + Send + Sync + 'static,
+              G: GeneGenerator<T> + 'static
+    {
+        PopulationConfigurationBuilder {
+            configuration: PopulationConfiguration {
+                population_size: 100,
+                fitness: Arc::new(fitness),
+                batch_fitness: None,
+                direction: Direction::Maximise,
+                generator: Arc::new(generator),
+                genenumber: 10,
+                min_genenumber: 10,
+                max_genenumber: 10,
+                mutation: Arc::new(RandomResetting),
+                mutation_rate: 0.1f32,
+                memetic: None,
+                crossover: Arc::new(UniformCrossover),
+                uniform_rate: 0.5f32,
+                selection: Arc::new(Tournament::new(4)),
+                elitism_size: 1,
+                replacement: Replacement::Generational,
+                niching: None,
+                eliminate_duplicates: false,
+                restart: None,
+                mutation_adaptation: None,
+                crossover_adaptation: None,
+                observers: Vec::new(),
+                threadpool_size: default_threadpool_size(),
+                cache: None,
+                objectives: None,
+                seed: None,
+            },
+            min_genenumber: None,
+            max_genenumber: None,
+        }
+    }
+
+    /// Preset for small problems: 32 Individuals, Tournament of 2 and
+    /// mutation_rate 0.2, evaluated on a single thread
+    pub fn small_search(self) -> PopulationConfigurationBuilder<T, S> {
+        self.population_size(32)
+            .selection(Tournament::new(2))
+            .mutation_rate(0.2f32)
+            .elitism_size(1)
+            .threadpool_size(1)
+    }
+
+    /// Preset which explores the search space: 200 Individuals, a low selection
+    /// pressure with Tournament of 2, mutation_rate 0.3 and duplicates elimination
+    pub fn exploratory(self) -> PopulationConfigurationBuilder<T, S> {
+        self.population_size(200)
+            .selection(Tournament::new(2))
+            .mutation_rate(0.3f32)
+            .elitism_size(1)
+            .eliminate_duplicates(true)
+    }
+
+    /// Preset which refines the best Individuals: 50 Individuals, an high selection
+    /// pressure with Tournament of 8, mutation_rate 0.05 and 5 elites
+    pub fn exploitative(self) -> PopulationConfigurationBuilder<T, S> {
+        self.population_size(50)
+            .selection(Tournament::new(8))
+            .mutation_rate(0.05f32)
+            .elitism_size(5)
+    }
+
+    /// Set the size of the Population
+    pub fn population_size(mut self,
+                           population_size: usize)
+                           -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.population_size = population_size;
+        self
+    }
+
+    /// Set the fitness called once with all the new Individuals of a generation, it has
+    /// to return their scores in order. The fitness of the builder is still used for
+    /// the Individuals evaluated alone
+    pub fn batch_fitness<B>(mut self, batch_fitness: B) -> PopulationConfigurationBuilder<T, S>
+        where B: Fn(&[Individual<T>]) -> Vec<S> + Send + Sync + 'static
+    {
+        self.configuration.batch_fitness = Some(Arc::new(batch_fitness));
+        self
+    }
+
+    /// Set the direction of the optimisation of the score
+    pub fn direction(mut self, direction: Direction) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.direction = direction;
+        self
+    }
+
+    /// Set the generator of the genes of the new Individuals and of the mutations
+    pub fn generator<G>(mut self, generator: G) -> PopulationConfigurationBuilder<T, S>
+        where G: GeneGenerator<T> + 'static
+    {
+        self.configuration.generator = Arc::new(generator);
+        self
+    }
+
+    /// Set the number of genes of each new Individual, it is also the minimum
+    /// and the maximum number of genes if they are not set
+    pub fn genenumber(mut self, genenumber: usize) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.genenumber = genenumber;
+        self
+    }
+
+    /// Set the minimum number of genes of the offsprings
+    pub fn min_genenumber(mut self, min_genenumber: usize) -> PopulationConfigurationBuilder<T, S> {
+        self.min_genenumber = Some(min_genenumber);
+        self
+    }
+
+    /// Set the maximum number of genes of the offsprings
+    pub fn max_genenumber(mut self, max_genenumber: usize) -> PopulationConfigurationBuilder<T, S> {
+        self.max_genenumber = Some(max_genenumber);
+        self
+    }
+
+    /// Set the operator used to create the offsprings from the parents
+    pub fn crossover<C>(mut self, crossover: C) -> PopulationConfigurationBuilder<T, S>
+        where C: Crossover<T> + 'static
+    {
+        self.configuration.crossover = Arc::new(crossover);
+        self
+    }
+
+    /// Set the parameter used by the crossover
+    pub fn uniform_rate(mut self, uniform_rate: f32) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.uniform_rate = uniform_rate;
+        self
+    }
+
+    /// Set the operator used to change the genes of the offsprings
+    pub fn mutation<M>(mut self, mutation: M) -> PopulationConfigurationBuilder<T, S>
+        where M: Mutation<T> + 'static
+    {
+        self.configuration.mutation = Arc::new(mutation);
+        self
+    }
+
+    /// Set the parameter used by the mutation
+    pub fn mutation_rate(mut self, mutation_rate: f32) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.mutation_rate = mutation_rate;
+        self
+    }
+
+    /// Set the local search applied to a fraction of the offsprings
+    pub fn memetic(mut self, memetic: Memetic<T, S>) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.memetic = Some(memetic);
+        self
+    }
+
+    /// Set the strategy used to select the parents of the offsprings
+    pub fn selection<L>(mut self, selection: L) -> PopulationConfigurationBuilder<T, S>
+        where L: Selection + 'static
+    {
+        self.configuration.selection = Arc::new(selection);
+        self
+    }
+
+    /// Set the number of Individuals copied in the next generation
+    pub fn elitism_size(mut self, elitism_size: usize) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.elitism_size = elitism_size;
+        self
+    }
+
+    /// Set the strategy used to build the next generation
+    pub fn replacement(mut self, replacement: Replacement) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.replacement = replacement;
+        self
+    }
+
+    /// Set the strategy used to keep different niches in the Population
+    pub fn niching(mut self, niching: Niching<T>) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.niching = Some(niching);
+        self
+    }
+
+    /// Set if the duplicated genomes are replaced with new random Individuals
+    pub fn eliminate_duplicates(mut self,
+                                eliminate_duplicates: bool)
+                                -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.eliminate_duplicates = eliminate_duplicates;
+        self
+    }
+
+    /// Set the strategy used to inject new Individuals when the best score stops improving
+    pub fn restart(mut self, restart: Restart) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.restart = Some(restart);
+        self
+    }
+
+    /// Set the schedule of the mutation_rate
+    pub fn mutation_adaptation<A>(mut self, adaptation: A) -> PopulationConfigurationBuilder<T, S>
+        where A: Adaptation + 'static
+    {
+        self.configuration.mutation_adaptation = Some(Arc::new(adaptation));
+        self
+    }
+
+    /// Set the schedule of the uniform_rate
+    pub fn crossover_adaptation<A>(mut self, adaptation: A) -> PopulationConfigurationBuilder<T, S>
+        where A: Adaptation + 'static
+    {
+        self.configuration.crossover_adaptation = Some(Arc::new(adaptation));
+        self
+    }
+
+    /// Add an Observer notified after the creation of each generation, an Arc
+    /// of an Observer can be shared between the islands of an Archipelago
+    pub fn observer<O>(mut self, observer: O) -> PopulationConfigurationBuilder<T, S>
+        where O: Observer + 'static
+    {
+        self.configuration.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Set the number of threads used to create the next generations
+    pub fn threadpool_size(mut self,
+                           threadpool_size: usize)
+                           -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.threadpool_size = threadpool_size;
+        self
+    }
+
+    /// Set the cache used to skip the fitness function for the genomes already evaluated
+    pub fn cache<C>(mut self, cache: C) -> PopulationConfigurationBuilder<T, S>
+        where C: FitnessCache<T, S> + 'static
+    {
+        self.configuration.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Set the objectives of the multi objective evolution
+    pub fn objectives<O>(mut self, objectives: O) -> PopulationConfigurationBuilder<T, S>
+        where O: Fn(&Individual<T>) -> Vec<f64> + Send + Sync + 'static
+    {
+        self.configuration.objectives = Some(Arc::new(objectives));
+        self
+    }
+
+    /// Set the seed of the random number generators
+    pub fn seed(mut self, seed: u64) -> PopulationConfigurationBuilder<T, S> {
+        self.configuration.seed = Some(seed);
+        self
+    }
+
+    /// Get the PopulationConfiguration, return an error
+    /// which contains every problem if it is not valid
+    pub fn build(self) -> Result<PopulationConfiguration<T, S>, Error> {
+        let mut c = self.configuration;
+        c.min_genenumber = self.min_genenumber.unwrap_or(c.genenumber);
+        c.max_genenumber = self.max_genenumber.unwrap_or(c.genenumber);
+        c.validate()?;
+        Ok(c)
+    }
+}
+
+impl<T, S> PopulationConfiguration<T, S>
+    where T: Clone + Rand + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    /// Get a PopulationConfigurationBuilder with the fitness and the defaults
+    pub fn builder<F>(fitness: F) -> PopulationConfigurationBuilder<T, S>
+        where F: Fn(&Individual<T>) -> S + Send + Sync + 'static
+    {
+        PopulationConfigurationBuilder::new(fitness)
+    }
+}
+
+impl<T, S> PopulationConfiguration<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    /// Get a PopulationConfigurationBuilder with the fitness,
+    /// the generator of the genes and the defaults
+    pub fn builder_with_generator<F, G>(fitness: F,
+                                        generator: G)
+                                        -> PopulationConfigurationBuilder<T, S>
+        where F: Fn(&Individual<T>) -> S + Send + Sync + 'static,
+              G: GeneGenerator<T> + 'static
+    {
+        PopulationConfigurationBuilder::with_generator(fitness, generator)
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use builder::*;
+use genetic::*;
+use genetic_planner::{PlannerConfiguration, PlannerConfigurationBuilder};
+use selection::Tournament;
+use mutation::GeneInsertionMutation;
+use score::Direction;
+use error::{ConfigurationError, Error};
+use observer::{Control, Event, Observer};
+
+#[allow(dead_code)]
+fn fitness(i: &Individual<u8>) -> i32 {
+    i.genes.iter().filter(|g| **g > 127).count() as i32
+}
+
+#[test]
+fn defaults() {
+    let c = PopulationConfiguration::builder(fitness).build().unwrap();
+    assert_eq!(c.population_size, 100);
+    assert_eq!(c.direction, Direction::Maximise);
+    assert_eq!((c.genenumber, c.min_genenumber, c.max_genenumber), (10, 10, 10));
+    assert_eq!(c.mutation_rate, 0.1f32);
+    assert_eq!(c.uniform_rate, 0.5f32);
+    assert_eq!(c.elitism_size, 1);
+    assert_eq!(c.threadpool_size, default_threadpool_size());
+    assert!(c.seed.is_none() && c.cache.is_none() && c.objectives.is_none());
+    let p = PlannerConfiguration::builder().build().unwrap();
+    assert_eq!((p.initial_actions, p.max_actions), (10, 20));
+    assert_eq!(p.population_size, 100);
+    assert_eq!(p.tournmant_size, 4);
+}
+
+#[test]
+fn setters() {
+    let shared: Arc<dyn Observer> = Arc::new(|_: &Event| Control::Continue);
+    let c = PopulationConfigurationBuilder::new(fitness)
+        .population_size(16)
+        .direction(Direction::Minimise)
+        .genenumber(4)
+        .max_genenumber(8)
+        .mutation(GeneInsertionMutation)
+        .mutation_rate(0.4f32)
+        .selection(Tournament::new(2))
+        .observer(|_: &Event| Control::Continue)
+        .observer(shared.clone())
+        .threadpool_size(2)
+        .seed(3)
+        .build()
+        .unwrap();
+    assert_eq!(c.population_size, 16);
+    assert_eq!(c.direction, Direction::Minimise);
+    assert_eq!((c.genenumber, c.min_genenumber, c.max_genenumber), (4, 4, 8));
+    assert_eq!(c.mutation_rate, 0.4f32);
+    assert_eq!(c.observers.len(), 2);
+    assert_eq!(c.seed, Some(3));
+    let p = Population::new(c).unwrap().evolve();
+    assert_eq!(p.individuals_and_scores.len(), 16);
+}
+
+#[test]
+fn presets() {
+    let small = PopulationConfiguration::builder(fitness).small_search().build().unwrap();
+    let exploratory = PopulationConfiguration::builder(fitness).exploratory().build().unwrap();
+    let exploitative = PopulationConfiguration::builder(fitness).exploitative().build().unwrap();
+    assert!(small.population_size < exploitative.population_size);
+    assert!(exploitative.population_size < exploratory.population_size);
+    assert!(exploratory.mutation_rate > exploitative.mutation_rate);
+    assert!(exploratory.eliminate_duplicates);
+    let c = PopulationConfiguration::builder(fitness)
+        .exploitative()
+        .population_size(20)
+        .build()
+        .unwrap();
+    assert_eq!((c.population_size, c.elitism_size), (20, 5));
+    for b in [PlannerConfigurationBuilder::new().small_search(),
+              PlannerConfigurationBuilder::new().exploratory(),
+              PlannerConfigurationBuilder::new().exploitative()] {
+        assert!(b.build().is_ok());
+    }
+}
+
+#[test]
+fn build_validates() {
+    let result = PopulationConfiguration::builder(fitness)
+        .population_size(0)
+        .mutation_rate(2f32)
+        .build();
+    match result {
+        Err(Error::InvalidConfiguration(problems)) => {
+            assert!(problems.contains(&ConfigurationError::EmptyPopulation));
+            assert_eq!(problems.len(), 3);
+        }
+        _ => panic!("the configuration is not valid"),
+    }
+    let result = PlannerConfiguration::builder().initial_actions(10).max_actions(5).build();
+    assert!(result.is_err());
+    let p = PlannerConfiguration::builder().initial_actions(6).build().unwrap();
+    assert_eq!(p.max_actions, 12);
+}
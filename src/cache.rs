@@ -0,0 +1,207 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use genetic::Individual;
+
+/// Number of hits and misses of a FitnessCache
+#[derive(Debug,Clone,Copy,PartialEq,Default)]
+pub struct CacheStatistics {
+    /// Number of scores found in the cache
+    pub hits: usize,
+    /// Number of scores not found in the cache
+    pub misses: usize,
+}
+
+/// Cache of the scores of the Individuals, used to skip the fitness
+/// function for the genomes already evaluated
+pub trait FitnessCache<T, S = i32>: Send + Sync {
+    /// Get the score of the Individual, if it is in the cache
+    fn get(&self, i: &Individual<T>) -> Option<S>;
+    /// Insert the score of the Individual in the cache
+    fn insert(&self, i: &Individual<T>, score: S);
+    /// Get the number of hits and misses of the cache
+    fn statistics(&self) -> CacheStatistics;
+    /// Called by the Population before the creation of a new generation
+    fn next_generation(&self) {}
+    /// Get, for each Individual, the position of the first one with the same genome
+    fn first_occurrences(&self, individuals: &[Individual<T>]) -> Vec<usize>;
+}
+
+/// Get, for each Individual, the position of the first one with the same genome,
+/// the genomes are indexed by their hash
+fn first_occurrences<T: Hash + Eq>(individuals: &[Individual<T>]) -> Vec<usize> {
+    let mut first: HashMap<&[T], usize> = HashMap::new();
+    individuals.iter().enumerate().map(|(n, i)| *first.entry(&i.genes[..]).or_insert(n)).collect()
+}
+
+/// Counters of the hits and misses shared by the caches
+#[derive(Debug,Default)]
+struct Counters {
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl Counters {
+    fn count<V>(&self, value: Option<V>) -> Option<V> {
+        match value {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        value
+    }
+
+    fn statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Entries of a LruCache, the genomes are ordered by the last use
+#[derive(Debug)]
+struct LruEntries<T, S> {
+    scores: HashMap<Vec<T>, (S, u64)>,
+    uses: BTreeMap<u64, Vec<T>>,
+    clock: u64,
+}
+
+/// FitnessCache which contains at most capacity genomes,
+/// the least recently used genome is removed when it is full
+#[derive(Debug)]
+pub struct LruCache<T, S = i32> {
+    /// Max number of genomes in the cache
+    pub capacity: usize,
+    entries: Mutex<LruEntries<T, S>>,
+    counters: Counters,
+}
+
+impl<T, S> LruCache<T, S>
+    where T: Clone + Hash + Eq
+{
+    /// Create a new LruCache of capacity genomes
+    pub fn new(capacity: usize) -> LruCache<T, S> {
+        LruCache {
+            capacity,
+            entries: Mutex::new(LruEntries {
+                scores: HashMap::new(),
+                uses: BTreeMap::new(),
+                clock: 0,
+            }),
+            counters: Counters::default(),
+        }
+    }
+
+    /// Get the number of genomes in the cache
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().scores.len()
+    }
+
+    /// Verify if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T, S> FitnessCache<T, S> for LruCache<T, S>
+    where T: Clone + Hash + Eq + Send + Sync,
+          S: Clone + Send + Sync
+{
+    fn get(&self, i: &Individual<T>) -> Option<S> {
+        let mut guard = self.entries.lock().unwrap();
+        let entries = &mut *guard;
+        entries.clock += 1;
+        let clock = entries.clock;
+        let score = match entries.scores.get_mut(&i.genes) {
+            Some(entry) => {
+                let genes = entries.uses.remove(&entry.1).unwrap();
+                entries.uses.insert(clock, genes);
+                entry.1 = clock;
+                Some(entry.0.clone())
+            }
+            None => None,
+        };
+        self.counters.count(score)
+    }
+
+    fn insert(&self, i: &Individual<T>, score: S) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut guard = self.entries.lock().unwrap();
+        let entries = &mut *guard;
+        entries.clock += 1;
+        let clock = entries.clock;
+        if let Some(old) = entries.scores.insert(i.genes.clone(), (score, clock)) {
+            entries.uses.remove(&old.1);
+        }
+        entries.uses.insert(clock, i.genes.clone());
+        while entries.scores.len() > self.capacity {
+            let oldest = *entries.uses.keys().next().unwrap();
+            let genes = entries.uses.remove(&oldest).unwrap();
+            entries.scores.remove(&genes);
+        }
+    }
+
+    fn statistics(&self) -> CacheStatistics {
+        self.counters.statistics()
+    }
+
+    fn first_occurrences(&self, individuals: &[Individual<T>]) -> Vec<usize> {
+        first_occurrences(individuals)
+    }
+}
+
+/// FitnessCache which contains only the genomes evaluated during the
+/// creation of the current generation, and at most capacity genomes
+#[derive(Debug)]
+pub struct GenerationCache<T, S = i32> {
+    /// Max number of genomes in the cache
+    pub capacity: usize,
+    scores: Mutex<HashMap<Vec<T>, S>>,
+    counters: Counters,
+}
+
+impl<T, S> GenerationCache<T, S>
+    where T: Clone + Hash + Eq
+{
+    /// Create a new GenerationCache of capacity genomes
+    pub fn new(capacity: usize) -> GenerationCache<T, S> {
+        GenerationCache {
+            capacity,
+            scores: Mutex::new(HashMap::new()),
+            counters: Counters::default(),
+        }
+    }
+}
+
+impl<T, S> FitnessCache<T, S> for GenerationCache<T, S>
+    where T: Clone + Hash + Eq + Send + Sync,
+          S: Clone + Send + Sync
+{
+    fn get(&self, i: &Individual<T>) -> Option<S> {
+        let score = self.scores.lock().unwrap().get(&i.genes).cloned();
+        self.counters.count(score)
+    }
+
+    fn insert(&self, i: &Individual<T>, score: S) {
+        let mut scores = self.scores.lock().unwrap();
+        if scores.len() < self.capacity {
+            scores.insert(i.genes.clone(), score);
+        }
+    }
+
+    fn statistics(&self) -> CacheStatistics {
+        self.counters.statistics()
+    }
+
+    fn first_occurrences(&self, individuals: &[Individual<T>]) -> Vec<usize> {
+        first_occurrences(individuals)
+    }
+
+    fn next_generation(&self) {
+        self.scores.lock().unwrap().clear();
+    }
+}
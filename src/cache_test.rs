@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use genetic::*;
+use cache::*;
+use selection::Tournament;
+use test_helpers::configuration_builder;
+
+#[allow(dead_code)]
+fn individual(v: u8) -> Individual<u8> {
+    Individual::new_with_vec(vec![v; 4])
+}
+
+#[test]
+fn lru_cache() {
+    let cache = LruCache::<u8>::new(2);
+    cache.insert(&individual(1), 1);
+    cache.insert(&individual(2), 2);
+    assert_eq!(cache.get(&individual(1)), Some(1));
+    cache.insert(&individual(3), 3);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&individual(2)), None);
+    assert_eq!(cache.get(&individual(1)), Some(1));
+    assert_eq!(cache.get(&individual(3)), Some(3));
+    assert_eq!(cache.statistics(),
+               CacheStatistics {
+                   hits: 3,
+                   misses: 1,
+               });
+}
+
+#[test]
+fn generation_cache() {
+    let cache = GenerationCache::<u8>::new(10);
+    cache.insert(&individual(1), 1);
+    assert_eq!(cache.get(&individual(1)), Some(1));
+    cache.next_generation();
+    assert_eq!(cache.get(&individual(1)), None);
+}
+
+#[test]
+fn first_occurrences() {
+    let individuals: Vec<Individual<u8>> =
+        [1, 2, 1, 3, 2, 1].iter().map(|v| individual(*v)).collect();
+    let expected = vec![0, 1, 0, 3, 1, 0];
+    assert_eq!(LruCache::<u8>::new(2).first_occurrences(&individuals), expected);
+    assert_eq!(GenerationCache::<u8>::new(2).first_occurrences(&individuals), expected);
+}
+
+#[test]
+fn population_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let fitness = move |i: &Individual<bool>| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        i.genes.iter().filter(|g| **g).count() as i32
+    };
+    let c = configuration_builder(fitness, 32, 2, 1)
+        .mutation_rate(0.1f32)
+        .cache(LruCache::new(16))
+        .build()
+        .unwrap();
+    let p = Population::new(c).unwrap().evolve().evolve();
+    let statistics = p.get_cache_statistics().unwrap();
+    assert_eq!(statistics.hits + statistics.misses, 32 + 31 + 31);
+    assert_eq!(statistics.misses, calls.load(Ordering::SeqCst));
+    assert!(statistics.hits > 0);
+}
+
+#[test]
+fn generation_cache_in_evolve() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let batches = Arc::new(AtomicUsize::new(0));
+    let batch_counter = batches.clone();
+    let fitness = |i: &Individual<bool>| i.genes.iter().filter(|g| **g).count() as i32;
+    let c = configuration_builder(fitness, 64, 2, 3)
+        .batch_fitness(move |individuals: &[Individual<bool>]| {
+            batch_counter.fetch_add(1, Ordering::SeqCst);
+            counter.fetch_add(individuals.len(), Ordering::SeqCst);
+            individuals.iter().map(fitness).collect()
+        })
+        .selection(Tournament::new(4))
+        .cache(GenerationCache::new(64))
+        .build()
+        .unwrap();
+    let mut p = Population::new(c).unwrap();
+    for _ in 0..5 {
+        p = p.evolve();
+    }
+    let statistics = p.get_cache_statistics().unwrap();
+    assert_eq!(statistics.hits + statistics.misses, 64 + 5 * 63);
+    assert_eq!(statistics.misses, calls.load(Ordering::SeqCst));
+    assert!(statistics.misses <= 6 * 4);
+    assert_eq!(batches.load(Ordering::SeqCst), 6);
+}
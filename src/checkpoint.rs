@@ -0,0 +1,308 @@
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+use std::str::FromStr;
+
+use genetic::{Individual, Population, PopulationConfiguration};
+use score::{Direction, Score};
+use replacement::{Replacement, Victim};
+use restart::Restart;
+use error::Error;
+
+/// First line of a checkpoint, with the version of the format
+const HEADER: &str = "genetic_planner population 1";
+
+/// Conversion of a gene to and from the text of a checkpoint,
+/// the text must not contain tabs or new lines
+pub trait GeneCodec<T>: Send + Sync {
+    /// Get the text of the gene
+    fn encode(&self, gene: &T) -> String;
+    /// Get the gene from its text, None if the text is not valid
+    fn decode(&self, text: &str) -> Option<T>;
+}
+
+/// GeneCodec of the genes which implement Display and FromStr, like the numbers
+#[derive(Debug,Clone,Default)]
+pub struct TextCodec<T> {
+    gene: PhantomData<T>,
+}
+
+impl<T> TextCodec<T> {
+    /// Create a new TextCodec
+    pub fn new() -> TextCodec<T> {
+        TextCodec { gene: PhantomData }
+    }
+}
+
+impl<T> GeneCodec<T> for TextCodec<T>
+    where T: Display + FromStr + Send + Sync
+{
+    fn encode(&self, gene: &T) -> String {
+        gene.to_string()
+    }
+
+    fn decode(&self, text: &str) -> Option<T> {
+        text.parse().ok()
+    }
+}
+
+/// Get the error of an invalid checkpoint with the message
+fn invalid(message: String) -> Error {
+    Error::InvalidCheckpoint(message)
+}
+
+/// Read the next line, missing is the description of the expected line
+fn next_line<R>(lines: &mut R, missing: &str) -> Result<String, Error>
+    where R: Iterator<Item = io::Result<String>>
+{
+    match lines.next() {
+        Some(line) => Ok(line?),
+        None => Err(invalid(format!("missing {}", missing))),
+    }
+}
+
+/// Get the text of the replacement
+fn replacement_text(replacement: Replacement) -> String {
+    match replacement {
+        Replacement::Generational => "generational".to_string(),
+        Replacement::SteadyState { offsprings, victim: Victim::Worst } => {
+            format!("steady_state {} worst", offsprings)
+        }
+        Replacement::SteadyState { offsprings, victim: Victim::TournamentLoser(size) } => {
+            format!("steady_state {} tournament_loser {}", offsprings, size)
+        }
+        Replacement::MuPlusLambda { offsprings } => format!("mu_plus_lambda {}", offsprings),
+        Replacement::MuCommaLambda { offsprings } => format!("mu_comma_lambda {}", offsprings),
+    }
+}
+
+/// Get the replacement from its text, None if the text is not valid
+fn parse_replacement(text: &str) -> Option<Replacement> {
+    let parts: Vec<&str> = text.split(' ').collect();
+    let number = |n: usize| parts.get(n).and_then(|p| p.parse().ok());
+    match (parts[0], parts.len()) {
+        ("generational", 1) => Some(Replacement::Generational),
+        ("steady_state", 3) if parts[2] == "worst" => {
+            Some(Replacement::SteadyState {
+                offsprings: number(1)?,
+                victim: Victim::Worst,
+            })
+        }
+        ("steady_state", 4) if parts[2] == "tournament_loser" => {
+            Some(Replacement::SteadyState {
+                offsprings: number(1)?,
+                victim: Victim::TournamentLoser(number(3)?),
+            })
+        }
+        ("mu_plus_lambda", 2) => Some(Replacement::MuPlusLambda { offsprings: number(1)? }),
+        ("mu_comma_lambda", 2) => Some(Replacement::MuCommaLambda { offsprings: number(1)? }),
+        _ => None,
+    }
+}
+
+/// Get the text of the restart
+fn restart_text(restart: Option<Restart>) -> String {
+    match restart {
+        None => "none".to_string(),
+        Some(Restart::RandomImmigrants { rate, patience }) => {
+            format!("random_immigrants {} {}", rate, patience)
+        }
+        Some(Restart::Catastrophe { patience }) => format!("catastrophe {}", patience),
+    }
+}
+
+/// Get the restart from its text, None if the text is not valid
+fn parse_restart(text: &str) -> Option<Option<Restart>> {
+    let parts: Vec<&str> = text.split(' ').collect();
+    match (parts[0], parts.len()) {
+        ("none", 1) => Some(None),
+        ("random_immigrants", 3) => {
+            Some(Some(Restart::RandomImmigrants {
+                rate: parts[1].parse().ok()?,
+                patience: parts[2].parse().ok()?,
+            }))
+        }
+        ("catastrophe", 2) => Some(Some(Restart::Catastrophe { patience: parts[1].parse().ok()? })),
+        _ => None,
+    }
+}
+
+/// Write the Population to the writer: the genomes, the scores, the counters and the
+/// parts of the configuration which are not functions or operators, including the
+/// replacement and the restart. Return an error, before writing anything, if the text
+/// of a gene contains a tab or a new line
+pub fn save<T, S, W>(pop: &Population<T, S>,
+                     writer: &mut W,
+                     codec: &dyn GeneCodec<T>)
+                     -> Result<(), Error>
+    where S: Display,
+          W: Write
+{
+    let mut lines = Vec::with_capacity(pop.individuals_and_scores.len());
+    for (i, score) in &pop.individuals_and_scores {
+        let mut line = score.to_string();
+        for gene in &i.genes {
+            let text = codec.encode(gene);
+            if text.contains(['\t', '\n', '\r']) {
+                return Err(invalid(format!("the text of a gene contains a tab or a new line: {:?}",
+                                           text)));
+            }
+            line.push('\t');
+            line.push_str(&text);
+        }
+        lines.push(line);
+    }
+    let c = &pop.configuration;
+    writeln!(writer, "{}", HEADER)?;
+    writeln!(writer, "generation {}", pop.generation)?;
+    writeln!(writer, "evaluations {}", pop.evaluations)?;
+    writeln!(writer, "stagnation {}", pop.stagnation)?;
+    writeln!(writer, "population_size {}", c.population_size)?;
+    writeln!(writer, "genenumber {}", c.genenumber)?;
+    writeln!(writer, "min_genenumber {}", c.min_genenumber)?;
+    writeln!(writer, "max_genenumber {}", c.max_genenumber)?;
+    writeln!(writer, "uniform_rate {}", c.uniform_rate)?;
+    writeln!(writer, "mutation_rate {}", c.mutation_rate)?;
+    writeln!(writer, "elitism_size {}", c.elitism_size)?;
+    writeln!(writer, "threadpool_size {}", c.threadpool_size)?;
+    writeln!(writer, "eliminate_duplicates {}", c.eliminate_duplicates)?;
+    writeln!(writer, "replacement {}", replacement_text(c.replacement))?;
+    writeln!(writer, "restart {}", restart_text(c.restart))?;
+    writeln!(writer,
+             "direction {}",
+             match c.direction {
+                 Direction::Maximise => "maximise",
+                 Direction::Minimise => "minimise",
+             })?;
+    match c.seed {
+        Some(seed) => writeln!(writer, "seed {}", seed)?,
+        None => writeln!(writer, "seed none")?,
+    }
+    writeln!(writer, "individuals {}", lines.len())?;
+    for line in lines {
+        writeln!(writer, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Read the value of the next line, which has to start with the key
+fn read_value<R, V>(lines: &mut R, key: &str) -> Result<V, Error>
+    where R: Iterator<Item = io::Result<String>>,
+          V: FromStr
+{
+    let line = next_line(lines, key)?;
+    let mut parts = line.splitn(2, ' ');
+    if parts.next() != Some(key) {
+        return Err(invalid(format!("expected {}, found {}", key, line)));
+    }
+    let value = parts.next().unwrap_or("");
+    value.parse().map_err(|_| invalid(format!("invalid {}: {}", key, value)))
+}
+
+/// Read a Population written by save. The functions and the operators of the configuration
+/// are kept, while the other parts are replaced by the saved ones. The scores are not
+/// evaluated again. Return an error if the saved configuration is not valid
+pub fn load<T, S, R>(reader: R,
+                     configuration: PopulationConfiguration<T, S>,
+                     codec: &dyn GeneCodec<T>)
+                     -> Result<Population<T, S>, Error>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score + FromStr,
+          R: BufRead
+{
+    let mut lines = reader.lines();
+    let header = next_line(&mut lines, "header")?;
+    if header != HEADER {
+        return Err(invalid(format!("unknown checkpoint format: {}", header)));
+    }
+    let mut c = configuration;
+    let generation = read_value(&mut lines, "generation")?;
+    let evaluations = read_value(&mut lines, "evaluations")?;
+    let stagnation = read_value(&mut lines, "stagnation")?;
+    c.population_size = read_value(&mut lines, "population_size")?;
+    c.genenumber = read_value(&mut lines, "genenumber")?;
+    c.min_genenumber = read_value(&mut lines, "min_genenumber")?;
+    c.max_genenumber = read_value(&mut lines, "max_genenumber")?;
+    c.uniform_rate = read_value(&mut lines, "uniform_rate")?;
+    c.mutation_rate = read_value(&mut lines, "mutation_rate")?;
+    c.elitism_size = read_value(&mut lines, "elitism_size")?;
+    c.threadpool_size = read_value(&mut lines, "threadpool_size")?;
+    c.eliminate_duplicates = read_value(&mut lines, "eliminate_duplicates")?;
+    let replacement: String = read_value(&mut lines, "replacement")?;
+    c.replacement = parse_replacement(&replacement)
+        .ok_or_else(|| invalid(format!("invalid replacement: {}", replacement)))?;
+    let restart: String = read_value(&mut lines, "restart")?;
+    c.restart = parse_restart(&restart)
+        .ok_or_else(|| invalid(format!("invalid restart: {}", restart)))?;
+    let direction: String = read_value(&mut lines, "direction")?;
+    c.direction = match direction.as_str() {
+        "maximise" => Direction::Maximise,
+        "minimise" => Direction::Minimise,
+        _ => return Err(invalid(format!("invalid direction: {}", direction))),
+    };
+    let seed: String = read_value(&mut lines, "seed")?;
+    c.seed = match seed.as_str() {
+        "none" => None,
+        _ => Some(seed.parse().map_err(|_| invalid(format!("invalid seed: {}", seed)))?),
+    };
+    let size: usize = read_value(&mut lines, "individuals")?;
+    let mut individuals_and_scores = Vec::with_capacity(size);
+    for _ in 0..size {
+        let line = next_line(&mut lines, "individual")?;
+        let mut parts = line.split('\t');
+        let score = parts.next().unwrap_or("");
+        let score: S = score.parse().map_err(|_| invalid(format!("invalid score: {}", score)))?;
+        let mut genes = Vec::new();
+        for text in parts {
+            let gene = codec.decode(text)
+                .ok_or_else(|| invalid(format!("invalid gene: {}", text)))?;
+            genes.push(gene);
+        }
+        individuals_and_scores.push((Individual::new_with_vec(genes), score));
+    }
+    let mut pop = Population::new_with_vec(individuals_and_scores, c, generation)?;
+    pop.evaluations = evaluations;
+    pop.stagnation = stagnation;
+    Ok(pop)
+}
+
+/// Save the Population in the file at path. The Population is written in a temporary
+/// file next to path, which replaces the file only when the Population is saved
+pub fn save_to_file<T, S, P>(pop: &Population<T, S>,
+                             path: P,
+                             codec: &dyn GeneCodec<T>)
+                             -> Result<(), Error>
+    where S: Display,
+          P: AsRef<Path>
+{
+    let path = path.as_ref();
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    let temporary = path.with_file_name(name);
+    let result = File::create(&temporary).map_err(Error::from).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        save(pop, &mut writer, codec)?;
+        Ok(writer.flush()?)
+    });
+    match result {
+        Ok(()) => Ok(fs::rename(&temporary, path)?),
+        Err(e) => {
+            let _ = fs::remove_file(&temporary);
+            Err(e)
+        }
+    }
+}
+
+/// Load the Population saved in the file at path
+pub fn load_from_file<T, S, P>(path: P,
+                               configuration: PopulationConfiguration<T, S>,
+                               codec: &dyn GeneCodec<T>)
+                               -> Result<Population<T, S>, Error>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score + FromStr,
+          P: AsRef<Path>
+{
+    load(BufReader::new(File::open(path)?), configuration, codec)
+}
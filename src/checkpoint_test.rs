@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use std::env;
+use std::io::Cursor;
+
+use genetic::*;
+use checkpoint::*;
+use test_helpers::configuration_builder;
+use mutation::GeneDeletionMutation;
+use score::{Direction, OrdF64};
+use error::Error;
+use replacement::{Replacement, Victim};
+use restart::Restart;
+
+#[allow(dead_code)]
+fn configuration() -> PopulationConfiguration<u8, OrdF64> {
+    let fitness = |i: &Individual<u8>| {
+        OrdF64(i.genes.iter().map(|g| *g as f64).sum::<f64>() / 3f64)
+    };
+    configuration_builder(fitness, 8, 4, 11)
+        .direction(Direction::Minimise)
+        .min_genenumber(1)
+        .max_genenumber(6)
+        .mutation(GeneDeletionMutation)
+        .mutation_rate(0.3f32)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn save_and_load() {
+    let p = Population::new(configuration()).unwrap().evolve().evolve();
+    let mut buffer: Vec<u8> = Vec::new();
+    save(&p, &mut buffer, &TextCodec::new()).unwrap();
+    let mut c = configuration();
+    c.population_size = 100;
+    c.seed = None;
+    let loaded = load(Cursor::new(buffer), c, &TextCodec::new()).unwrap();
+    assert_eq!(loaded.individuals_and_scores, p.individuals_and_scores);
+    assert_eq!(loaded.generation, 2);
+    assert_eq!(loaded.evaluations, p.evaluations);
+    assert_eq!(loaded.configuration.population_size, 8);
+    assert_eq!(loaded.configuration.seed, Some(11));
+    assert_eq!(loaded.configuration.direction, Direction::Minimise);
+    assert_eq!(loaded.evolve().individuals_and_scores,
+               p.evolve().individuals_and_scores);
+}
+
+#[test]
+fn file() {
+    let path = env::temp_dir().join("genetic_planner_checkpoint_test.txt");
+    let p = Population::new(configuration()).unwrap();
+    save_to_file(&p, &path, &TextCodec::new()).unwrap();
+    let loaded = load_from_file(&path, configuration(), &TextCodec::new()).unwrap();
+    assert_eq!(loaded.individuals_and_scores, p.individuals_and_scores);
+    ::std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn invalid_checkpoint() {
+    let codec = TextCodec::new();
+    assert!(load(Cursor::new("something else\n"), configuration(), &codec).is_err());
+    let p = Population::new(configuration()).unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    save(&p, &mut buffer, &codec).unwrap();
+    let text = String::from_utf8(buffer).unwrap().replace("\t", "\tx");
+    assert!(load(Cursor::new(text), configuration(), &codec).is_err());
+}
+
+/// GeneCodec which writes the genes 0 with a tab
+struct TabCodec;
+
+impl GeneCodec<u8> for TabCodec {
+    fn encode(&self, gene: &u8) -> String {
+        if *gene == 0 { "zero\tgene".to_string() } else { gene.to_string() }
+    }
+
+    fn decode(&self, text: &str) -> Option<u8> {
+        text.parse().ok()
+    }
+}
+
+#[test]
+fn invalid_gene_text() {
+    let genes = vec![(Individual::new_with_vec(vec![1u8, 0, 2]), OrdF64(1f64))];
+    let p = Population::new_with_vec(genes, configuration(), 0).unwrap();
+    let mut buffer: Vec<u8> = Vec::new();
+    match save(&p, &mut buffer, &TabCodec) {
+        Err(Error::InvalidCheckpoint(message)) => assert!(message.contains("zero\\tgene")),
+        _ => panic!("the gene with a tab has been saved"),
+    }
+    let genes = vec![(Individual::new_with_vec(vec![1u8, 2]), OrdF64(1f64))];
+    let p = Population::new_with_vec(genes, configuration(), 0).unwrap();
+    assert!(save(&p, &mut Vec::new(), &TabCodec).is_ok());
+}
+
+#[test]
+fn replacement_and_restart() {
+    let replacements = [Replacement::Generational,
+                        Replacement::SteadyState {
+                            offsprings: 3,
+                            victim: Victim::TournamentLoser(2),
+                        },
+                        Replacement::MuCommaLambda { offsprings: 12 }];
+    let restarts = [None,
+                    Some(Restart::RandomImmigrants {
+                        rate: 0.25f32,
+                        patience: 4,
+                    }),
+                    Some(Restart::Catastrophe { patience: 7 })];
+    for (replacement, restart) in replacements.iter().zip(restarts.iter()) {
+        let mut c = configuration();
+        c.replacement = *replacement;
+        c.restart = *restart;
+        let p = Population::new(c).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        save(&p, &mut buffer, &TextCodec::new()).unwrap();
+        let loaded = load(Cursor::new(buffer), configuration(), &TextCodec::new()).unwrap();
+        assert_eq!(loaded.configuration.replacement, *replacement);
+        assert_eq!(loaded.configuration.restart, *restart);
+    }
+}
+
+#[test]
+fn failed_save_keeps_file() {
+    let path = env::temp_dir().join("genetic_planner_failed_checkpoint_test.txt");
+    let p = Population::new(configuration()).unwrap();
+    save_to_file(&p, &path, &TextCodec::new()).unwrap();
+    let genes = vec![(Individual::new_with_vec(vec![0u8]), OrdF64(0f64))];
+    let invalid = Population::new_with_vec(genes, configuration(), 0).unwrap();
+    assert!(save_to_file(&invalid, &path, &TabCodec).is_err());
+    let loaded = load_from_file(&path, configuration(), &TextCodec::new()).unwrap();
+    assert_eq!(loaded.individuals_and_scores, p.individuals_and_scores);
+    ::std::fs::remove_file(&path).unwrap();
+}
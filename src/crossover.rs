@@ -0,0 +1,378 @@
+extern crate rand;
+use rand::{Rng, XorShiftRng};
+
+use genetic::Individual;
+
+/// Operator used to combine two parents in one or two offsprings
+pub trait Crossover<T>: Send + Sync {
+    /// Return the offsprings of i1 and i2, rate is the crossover parameter
+    /// of the configuration
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 rate: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>>;
+
+    /// Number of offsprings returned by the crossover function
+    fn children(&self) -> usize {
+        1
+    }
+}
+
+/// Take each gene from the first parent with probability rate,
+/// otherwise from the second parent. The offspring takes the length of
+/// the first parent with probability rate, the genes after the end of
+/// the shorter parent are copied from the longer one
+#[derive(Debug,Clone)]
+pub struct UniformCrossover;
+
+impl<T> Crossover<T> for UniformCrossover
+    where T: Clone + Send + Sync
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 rate: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        let longer = if i1.genes.len() > i2.genes.len() { i1 } else { i2 };
+        let len = if rng.gen_range(0f32, 1f32) < rate {
+            i1.genes.len()
+        } else {
+            i2.genes.len()
+        };
+        let mut genes: Vec<T> = i1.genes
+            .iter()
+            .zip(i2.genes.iter())
+            .map(|(g1, g2)| if rng.gen_range(0f32, 1f32) < rate {
+                g1.clone()
+            } else {
+                g2.clone()
+            })
+            .collect();
+        let common = genes.len();
+        if len > common {
+            genes.extend_from_slice(&longer.genes[common..len]);
+        }
+        vec![Individual { genes }]
+    }
+}
+
+/// Cut the parents in points random positions and swap the
+/// alternate segments, the contiguous sub sequences are preserved.
+/// The cut points are chosen in the common part of the parents,
+/// the tail of the longer parent follows its last segment
+#[derive(Debug,Clone)]
+pub struct KPointCrossover {
+    /// Number of cut points
+    pub points: usize,
+}
+
+impl KPointCrossover {
+    /// Create a new KPointCrossover with points cut points
+    pub fn new(points: usize) -> KPointCrossover {
+        KPointCrossover { points }
+    }
+}
+
+impl<T> Crossover<T> for KPointCrossover
+    where T: Clone + Send + Sync
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 _: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        let len = i1.genes.len().min(i2.genes.len());
+        let mut cuts: Vec<usize> = (1..len.max(1)).collect();
+        let points = self.points.min(cuts.len());
+        for i in 0..points {
+            let j = rng.gen_range(i, cuts.len());
+            cuts.swap(i, j);
+        }
+        cuts.truncate(points);
+        cuts.sort();
+        let mut c1 = Vec::with_capacity(i1.genes.len());
+        let mut c2 = Vec::with_capacity(i2.genes.len());
+        let mut swap = false;
+        let mut cut = cuts.iter().peekable();
+        for i in 0..i1.genes.len().max(i2.genes.len()) {
+            while cut.peek().is_some_and(|c| **c == i) {
+                swap = !swap;
+                cut.next();
+            }
+            let (g1, g2) = if swap {
+                (i2.genes.get(i), i1.genes.get(i))
+            } else {
+                (i1.genes.get(i), i2.genes.get(i))
+            };
+            c1.extend(g1.cloned());
+            c2.extend(g2.cloned());
+        }
+        vec![Individual { genes: c1 }, Individual { genes: c2 }]
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// KPointCrossover with a single cut point
+#[derive(Debug,Clone)]
+pub struct OnePointCrossover;
+
+impl<T> Crossover<T> for OnePointCrossover
+    where T: Clone + Send + Sync
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 rate: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        KPointCrossover::new(1).crossover(i1, i2, rate, rng)
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// KPointCrossover with two cut points
+#[derive(Debug,Clone)]
+pub struct TwoPointCrossover;
+
+impl<T> Crossover<T> for TwoPointCrossover
+    where T: Clone + Send + Sync
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 rate: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        KPointCrossover::new(2).crossover(i1, i2, rate, rng)
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// Cut each parent in an independent random position and join the head of
+/// each parent with the tail of the other one, the offsprings can be
+/// longer or shorter than the parents
+#[derive(Debug,Clone)]
+pub struct CutAndSpliceCrossover;
+
+impl<T> Crossover<T> for CutAndSpliceCrossover
+    where T: Clone + Send + Sync
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 _: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        let a = rng.gen_range(0, i1.genes.len() + 1);
+        let b = rng.gen_range(0, i2.genes.len() + 1);
+        let mut c1 = i1.genes[..a].to_vec();
+        c1.extend_from_slice(&i2.genes[b..]);
+        let mut c2 = i2.genes[..b].to_vec();
+        c2.extend_from_slice(&i1.genes[a..]);
+        vec![Individual { genes: c1 }, Individual { genes: c2 }]
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// Get two random positions a <= b of a genome of len genes
+fn segment(len: usize, rng: &mut XorShiftRng) -> (usize, usize) {
+    let a = rng.gen_range(0, len + 1);
+    let b = rng.gen_range(0, len + 1);
+    if a < b { (a, b) } else { (b, a) }
+}
+
+/// Verify if the permutation operators can be applied to the parents
+fn same_len<T>(i1: &Individual<T>, i2: &Individual<T>) -> bool {
+    !i1.genes.is_empty() && i1.genes.len() == i2.genes.len()
+}
+
+/// Order crossover (OX) for permutation genomes: the child keeps a segment
+/// of the first parent and the relative order of the other genes of the second
+#[derive(Debug,Clone)]
+pub struct OrderCrossover;
+
+/// Verify if p2 contains the same genes of p1, in any order
+fn is_permutation<T: PartialEq>(p1: &[T], p2: &[T]) -> bool {
+    let mut used = vec![false; p2.len()];
+    p1.len() == p2.len() &&
+    p1.iter().all(|g| {
+        match (0..p2.len()).find(|i| !used[*i] && p2[*i] == *g) {
+            Some(i) => {
+                used[i] = true;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
+/// Get the child of the order crossover between p1 and p2 on the segment a..b,
+/// a copy of p1 if the segment is the whole genome or the parents are not
+/// permutations of the same genes
+fn order_child<T>(p1: &[T], p2: &[T], a: usize, b: usize) -> Vec<T>
+    where T: Clone + PartialEq
+{
+    let len = p1.len();
+    if b - a == len || !is_permutation(p1, p2) {
+        return p1.to_vec();
+    }
+    let mut used = vec![false; len];
+    let mut child: Vec<Option<T>> = vec![None; len];
+    for i in a..b {
+        child[i] = Some(p1[i].clone());
+    }
+    let mut position = b % len;
+    for k in 0..len {
+        let g = &p2[(b + k) % len];
+        let in_segment = (a..b).find(|i| !used[*i] && p1[*i] == *g);
+        match in_segment {
+            Some(i) => used[i] = true,
+            None => {
+                while position >= a && position < b {
+                    position = (position + 1) % len;
+                }
+                child[position] = Some(g.clone());
+                position = (position + 1) % len;
+            }
+        }
+    }
+    child.into_iter().map(|g| g.unwrap()).collect()
+}
+
+impl<T> Crossover<T> for OrderCrossover
+    where T: Clone + Send + Sync + PartialEq
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 _: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        if !same_len(i1, i2) {
+            return vec![i1.clone(), i2.clone()];
+        }
+        let (a, b) = segment(i1.genes.len(), rng);
+        vec![Individual { genes: order_child(&i1.genes, &i2.genes, a, b) },
+             Individual { genes: order_child(&i2.genes, &i1.genes, a, b) }]
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// Partially mapped crossover (PMX) for permutation genomes: the child keeps a
+/// segment of the first parent and the positions of the other genes of the second
+#[derive(Debug,Clone)]
+pub struct PartiallyMappedCrossover;
+
+/// Get the child of the partially mapped crossover between p1 and p2 on the segment a..b
+fn pmx_child<T>(p1: &[T], p2: &[T], a: usize, b: usize) -> Vec<T>
+    where T: Clone + PartialEq
+{
+    let mut child = p2.to_vec();
+    child[a..b].clone_from_slice(&p1[a..b]);
+    for i in a..b {
+        if p1[a..b].contains(&p2[i]) {
+            continue;
+        }
+        let mut j = i;
+        for _ in 0..p1.len() {
+            match p2.iter().position(|g| *g == p1[j]) {
+                Some(k) if k >= a && k < b => j = k,
+                Some(k) => {
+                    child[k] = p2[i].clone();
+                    break;
+                }
+                None => break,
+            }
+        }
+    }
+    child
+}
+
+impl<T> Crossover<T> for PartiallyMappedCrossover
+    where T: Clone + Send + Sync + PartialEq
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 _: f32,
+                 rng: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        if !same_len(i1, i2) {
+            return vec![i1.clone(), i2.clone()];
+        }
+        let (a, b) = segment(i1.genes.len(), rng);
+        vec![Individual { genes: pmx_child(&i1.genes, &i2.genes, a, b) },
+             Individual { genes: pmx_child(&i2.genes, &i1.genes, a, b) }]
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
+
+/// Cycle crossover (CX) for permutation genomes: each gene keeps the position
+/// it has in one of the parents, the cycles are taken alternatively from each parent
+#[derive(Debug,Clone)]
+pub struct CycleCrossover;
+
+impl<T> Crossover<T> for CycleCrossover
+    where T: Clone + Send + Sync + PartialEq
+{
+    fn crossover(&self,
+                 i1: &Individual<T>,
+                 i2: &Individual<T>,
+                 _: f32,
+                 _: &mut XorShiftRng)
+                 -> Vec<Individual<T>> {
+        if !same_len(i1, i2) {
+            return vec![i1.clone(), i2.clone()];
+        }
+        let len = i1.genes.len();
+        let mut c1 = i1.genes.clone();
+        let mut c2 = i2.genes.clone();
+        let mut visited = vec![false; len];
+        let mut swap = false;
+        for start in 0..len {
+            if visited[start] {
+                continue;
+            }
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                if swap {
+                    c1[i] = i2.genes[i].clone();
+                    c2[i] = i1.genes[i].clone();
+                }
+                match i1.genes.iter().position(|g| *g == i2.genes[i]) {
+                    Some(j) => i = j,
+                    None => break,
+                }
+            }
+            swap = !swap;
+        }
+        vec![Individual { genes: c1 }, Individual { genes: c2 }]
+    }
+
+    fn children(&self) -> usize {
+        2
+    }
+}
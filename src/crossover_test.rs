@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+extern crate rand;
+use rand::{SeedableRng, XorShiftRng};
+
+use genetic::Individual;
+use crossover::*;
+
+#[allow(dead_code)]
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[allow(dead_code)]
+fn is_permutation(i: &Individual<u8>, len: u8) -> bool {
+    let mut genes = i.genes.clone();
+    genes.sort();
+    genes == (0..len).collect::<Vec<u8>>()
+}
+
+#[test]
+fn k_point_crossover() {
+    let i1 = Individual::new_with_vec(vec![0u8; 10]);
+    let i2 = Individual::new_with_vec(vec![1u8; 10]);
+    let mut r = rng();
+    for points in 1..5 {
+        let c = KPointCrossover::new(points).crossover(&i1, &i2, 0.5, &mut r);
+        assert_eq!(c.len(), 2);
+        let changes = c[0].genes.windows(2).filter(|w| w[0] != w[1]).count();
+        assert_eq!(changes, points);
+        for i in 0..10 {
+            assert!(c[0].genes[i] != c[1].genes[i]);
+        }
+    }
+}
+
+#[test]
+fn permutation_crossovers() {
+    let i1 = Individual::new_with_vec(vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let i2 = Individual::new_with_vec(vec![9u8, 3, 7, 8, 2, 6, 5, 1, 4, 0]);
+    let crossovers: Vec<Box<dyn Crossover<u8>>> = vec![Box::new(OrderCrossover),
+                                                       Box::new(PartiallyMappedCrossover),
+                                                       Box::new(CycleCrossover)];
+    let mut r = rng();
+    for c in crossovers {
+        for _ in 0..20 {
+            for child in c.crossover(&i1, &i2, 0.5, &mut r) {
+                assert!(is_permutation(&child, 10));
+            }
+        }
+    }
+}
+
+#[test]
+fn cycle_crossover_positions() {
+    let i1 = Individual::new_with_vec(vec![0u8, 1, 2, 3, 4, 5, 6, 7]);
+    let i2 = Individual::new_with_vec(vec![1u8, 0, 3, 2, 5, 4, 7, 6]);
+    let c = CycleCrossover.crossover(&i1, &i2, 0.5, &mut rng());
+    assert_eq!(c[0].genes, vec![0u8, 1, 3, 2, 4, 5, 7, 6]);
+    assert_eq!(c[1].genes, vec![1u8, 0, 2, 3, 5, 4, 6, 7]);
+}
+
+#[test]
+fn variable_length_crossovers() {
+    let i1 = Individual::new_with_vec(vec![0u8; 4]);
+    let i2 = Individual::new_with_vec(vec![1u8; 9]);
+    let mut r = rng();
+    for _ in 0..20 {
+        let c = CutAndSpliceCrossover.crossover(&i1, &i2, 0.5, &mut r);
+        assert_eq!(c[0].genes.len() + c[1].genes.len(), 13);
+        let k = TwoPointCrossover.crossover(&i1, &i2, 0.5, &mut r);
+        assert_eq!(k[0].genes.len() + k[1].genes.len(), 13);
+        let u = UniformCrossover.crossover(&i1, &i2, 0.5, &mut r);
+        assert!(u[0].genes.len() == 4 || u[0].genes.len() == 9);
+    }
+    let u = UniformCrossover.crossover(&i1, &i2, 0f32, &mut r);
+    assert_eq!(u[0], i2);
+}
+
+#[test]
+fn order_crossover_not_permutations() {
+    let i1 = Individual::new_with_vec(vec![1u8, 2, 3]);
+    let i2 = Individual::new_with_vec(vec![4u8, 5, 6]);
+    let p1 = Individual::new_with_vec(vec![0u8, 1]);
+    let p2 = Individual::new_with_vec(vec![1u8, 0]);
+    for seed in 0..50 {
+        let mut r = ::genetic::seeded_rng(seed, 0);
+        let c = OrderCrossover.crossover(&i1, &i2, 0.5, &mut r);
+        assert!(c[0] == i1 && c[1] == i2);
+        for child in OrderCrossover.crossover(&p1, &p2, 0.5, &mut r) {
+            assert!(is_permutation(&child, 2));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// A problem of a PopulationConfiguration or of a PlannerConfiguration
+#[derive(Debug,Clone,PartialEq)]
+pub enum ConfigurationError {
+    /// The population_size is 0
+    EmptyPopulation,
+    /// The size of the tournament is 0
+    EmptyTournament,
+    /// A rate, like the mutation_rate, is not between 0 and 1
+    RateOutOfRange {
+        /// Name of the field
+        name: &'static str,
+        /// Value of the field
+        value: f32,
+    },
+    /// The elitism_size is greater than the population_size
+    ElitismTooLarge {
+        /// Number of Individuals copied in the next generation
+        elitism_size: usize,
+        /// Size of the Population
+        population_size: usize,
+    },
+    /// The number of genes of the new Individuals is not
+    /// between the minimum and the maximum
+    InvalidGeneNumber {
+        /// Number of genes of each new Individual
+        genenumber: usize,
+        /// Minimum number of genes
+        min_genenumber: usize,
+        /// Maximum number of genes
+        max_genenumber: usize,
+    },
+    /// The replacement creates no offsprings, or the (mu, lambda)
+    /// replacement creates less offsprings than the population_size
+    TooFewOffsprings {
+        /// Number of offsprings created by each evolve
+        offsprings: usize,
+        /// Size of the Population
+        population_size: usize,
+    },
+    /// The patience of the catastrophe restart is 0
+    NoPatience,
+    /// The islands of an Archipelago do not optimise their scores in the same direction
+    DifferentDirections,
+    /// The initial_actions of the planner are 0 or more than max_actions
+    InvalidActionNumber {
+        /// Number of actions of the initial plans
+        initial_actions: usize,
+        /// Max number of actions of a plan
+        max_actions: usize,
+    },
+    /// The selection_pressure of the RankBased selection is not between 1 and 2
+    SelectionPressureOutOfRange(f64),
+    /// A weight of the WeightedMutation is negative or not finite
+    InvalidWeight(f64),
+    /// A Termination All or Any has no criteria
+    EmptyTermination,
+}
+
+impl fmt::Display for ConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigurationError::EmptyPopulation => write!(f, "population_size must be at least 1"),
+            ConfigurationError::EmptyTournament => write!(f, "tournament size must be at least 1"),
+            ConfigurationError::RateOutOfRange { name, value } => {
+                write!(f, "{} must be between 0 and 1, it is {}", name, value)
+            }
+            ConfigurationError::ElitismTooLarge { elitism_size, population_size } => {
+                write!(f,
+                       "elitism_size {} is greater than population_size {}",
+                       elitism_size,
+                       population_size)
+            }
+            ConfigurationError::InvalidGeneNumber { genenumber,
+                                                    min_genenumber,
+                                                    max_genenumber } => {
+                write!(f,
+                       "genenumber {} is not between min_genenumber {} and max_genenumber {}",
+                       genenumber,
+                       min_genenumber,
+                       max_genenumber)
+            }
+            ConfigurationError::TooFewOffsprings { offsprings, population_size } => {
+                write!(f,
+                       "{} offsprings can not replace a population of {}",
+                       offsprings,
+                       population_size)
+            }
+            ConfigurationError::NoPatience => write!(f, "catastrophe patience must be at least 1"),
+            ConfigurationError::DifferentDirections => {
+                write!(f, "the islands must have the same direction")
+            }
+            ConfigurationError::InvalidActionNumber { initial_actions, max_actions } => {
+                write!(f,
+                       "initial_actions {} is not between 1 and max_actions {}",
+                       initial_actions,
+                       max_actions)
+            }
+            ConfigurationError::SelectionPressureOutOfRange(value) => {
+                write!(f, "selection_pressure must be between 1 and 2, it is {}", value)
+            }
+            ConfigurationError::InvalidWeight(value) => {
+                write!(f, "mutation weight must be finite and not negative, it is {}", value)
+            }
+            ConfigurationError::EmptyTermination => {
+                write!(f, "termination all and any must have at least one criterion")
+            }
+        }
+    }
+}
+
+/// Error returned by the functions of the crate
+#[derive(Debug)]
+pub enum Error {
+    /// The configuration is not valid, contains every problem found
+    InvalidConfiguration(Vec<ConfigurationError>),
+    /// The Population has no Individuals
+    EmptyPopulation,
+    /// The checkpoint can not be read or written
+    Io(io::Error),
+    /// The checkpoint has not the expected format
+    InvalidCheckpoint(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidConfiguration(ref problems) => {
+                let problems: Vec<String> = problems.iter().map(|p| p.to_string()).collect();
+                write!(f, "invalid configuration: {}", problems.join(", "))
+            }
+            Error::EmptyPopulation => write!(f, "the population has no individuals"),
+            Error::Io(ref e) => write!(f, "checkpoint error: {}", e),
+            Error::InvalidCheckpoint(ref message) => write!(f, "invalid checkpoint: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+/// Get the problem of a rate, if it is not between 0 and 1
+pub fn check_rate(name: &'static str, value: f32) -> Option<ConfigurationError> {
+    if (0f32..=1f32).contains(&value) {
+        None
+    } else {
+        Some(ConfigurationError::RateOutOfRange { name, value })
+    }
+}
+
+/// Get Ok if there are no problems, otherwise the error which contains all of them
+pub fn check(problems: Vec<ConfigurationError>) -> Result<(), Error> {
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidConfiguration(problems))
+    }
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use error::*;
+use genetic::*;
+use generator::RandomGenes;
+use genetic_planner::PlannerConfiguration;
+use score::Direction;
+use replacement::Replacement;
+use selection::{RankBased, Tournament, Truncation};
+use crossover::UniformCrossover;
+use mutation::{RandomResetting, SwapMutation, WeightedMutation};
+
+#[allow(dead_code)]
+fn configuration() -> PopulationConfiguration<u8> {
+    PopulationConfiguration {
+        population_size: 8,
+        fitness: Arc::new(|i: &Individual<u8>| i.genes[0] as i32),
+        batch_fitness: None,
+        direction: Direction::Maximise,
+        generator: Arc::new(RandomGenes),
+        genenumber: 4,
+        min_genenumber: 1,
+        max_genenumber: 4,
+        mutation: Arc::new(RandomResetting),
+        mutation_rate: 0.2f32,
+        memetic: None,
+        crossover: Arc::new(UniformCrossover),
+        uniform_rate: 0.5f32,
+        selection: Arc::new(Tournament::new(2)),
+        elitism_size: 1,
+        replacement: Replacement::Generational,
+        niching: None,
+        eliminate_duplicates: false,
+        restart: None,
+        mutation_adaptation: None,
+        crossover_adaptation: None,
+        observers: Vec::new(),
+        threadpool_size: 1,
+        cache: None,
+        objectives: None,
+        seed: Some(1),
+    }
+}
+
+#[allow(dead_code)]
+fn planner_configuration() -> PlannerConfiguration {
+    PlannerConfiguration {
+        initial_actions: 4,
+        max_actions: 8,
+        population_size: 16,
+        tournmant_size: 4,
+        elitism_size: 1,
+        uniform_rate: 0.5,
+        mutation_rate: 0.5,
+        threadpool_size: 1,
+        seed: None,
+        restart: None,
+    }
+}
+
+#[allow(dead_code)]
+fn problems(result: Result<(), Error>) -> Vec<ConfigurationError> {
+    match result {
+        Err(Error::InvalidConfiguration(problems)) => problems,
+        Err(e) => panic!("unexpected error {}", e),
+        Ok(()) => Vec::new(),
+    }
+}
+
+#[test]
+fn valid_configuration() {
+    assert!(configuration().validate().is_ok());
+    assert!(planner_configuration().validate().is_ok());
+}
+
+#[test]
+fn invalid_population_configuration() {
+    let mut c = configuration();
+    c.population_size = 0;
+    c.genenumber = 6;
+    c.mutation_rate = 1.5f32;
+    c.uniform_rate = -0.1f32;
+    c.selection = Arc::new(Tournament::new(0));
+    assert_eq!(problems(c.validate()),
+               vec![ConfigurationError::EmptyPopulation,
+                    ConfigurationError::ElitismTooLarge {
+                        elitism_size: 1,
+                        population_size: 0,
+                    },
+                    ConfigurationError::InvalidGeneNumber {
+                        genenumber: 6,
+                        min_genenumber: 1,
+                        max_genenumber: 4,
+                    },
+                    ConfigurationError::EmptyTournament,
+                    ConfigurationError::RateOutOfRange {
+                        name: "mutation_rate",
+                        value: 1.5f32,
+                    },
+                    ConfigurationError::RateOutOfRange {
+                        name: "uniform_rate",
+                        value: -0.1f32,
+                    }]);
+    assert!(Population::new(c).is_err());
+    let mut c = configuration();
+    c.selection = Arc::new(Truncation::new(1.5));
+    assert_eq!(problems(c.validate()),
+               vec![ConfigurationError::RateOutOfRange {
+                        name: "truncation proportion",
+                        value: 1.5f32,
+                    }]);
+    let mut c = configuration();
+    c.selection = Arc::new(RankBased::new(2.5));
+    assert_eq!(problems(c.validate()),
+               vec![ConfigurationError::SelectionPressureOutOfRange(2.5)]);
+    c.selection = Arc::new(RankBased::new(1.5));
+    c.mutation = Arc::new(WeightedMutation::new().add(1f64, SwapMutation).add(-1f64, SwapMutation));
+    assert_eq!(problems(c.validate()), vec![ConfigurationError::InvalidWeight(-1f64)]);
+    c.mutation = Arc::new(WeightedMutation::new().add(f64::NAN, SwapMutation));
+    assert!(Population::new(c).is_err());
+}
+
+#[test]
+fn invalid_planner_configuration() {
+    let mut c = planner_configuration();
+    c.tournmant_size = 0;
+    c.elitism_size = 20;
+    c.initial_actions = 10;
+    let problems = problems(c.validate());
+    assert_eq!(problems.len(), 3);
+    assert!(problems.contains(&ConfigurationError::EmptyTournament));
+    assert!(problems.contains(&ConfigurationError::InvalidActionNumber {
+        initial_actions: 10,
+        max_actions: 8,
+    }));
+}
+
+#[test]
+fn display() {
+    let e = Error::InvalidConfiguration(vec![ConfigurationError::EmptyPopulation,
+                                             ConfigurationError::EmptyTournament]);
+    assert_eq!(e.to_string(),
+               "invalid configuration: population_size must be at least 1, \
+                tournament size must be at least 1");
+}
+
+#[test]
+fn empty_population() {
+    let p = Population::new_with_vec(Vec::new(), configuration(), 0).unwrap();
+    let pe = p.evolve();
+    assert!(pe.individuals_and_scores.is_empty());
+    assert!(pe.get_fittest().is_none());
+}
@@ -0,0 +1,48 @@
+extern crate rand;
+use rand::{Rand, Rng, XorShiftRng};
+
+/// Context of the creation of a gene
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub struct GeneContext {
+    /// Position of the gene in the genome
+    pub position: usize,
+    /// Number of genes of the genome which contains the gene
+    pub genenumber: usize,
+}
+
+/// Creates the random genes of the new Individuals and of the mutations.
+/// The problem instance, if needed, can be captured by the generator
+pub trait GeneGenerator<T>: Send + Sync {
+    /// Create a new random gene for the context
+    fn generate(&self, context: &GeneContext, rng: &mut XorShiftRng) -> T;
+}
+
+impl<T, F> GeneGenerator<T> for F
+    where F: Fn(&GeneContext, &mut XorShiftRng) -> T + Send + Sync
+{
+    fn generate(&self, context: &GeneContext, rng: &mut XorShiftRng) -> T {
+        self(context, rng)
+    }
+}
+
+/// GeneGenerator of the genes which implement Rand, the context is not used
+#[derive(Debug,Clone,Copy)]
+pub struct RandomGenes;
+
+impl<T> GeneGenerator<T> for RandomGenes
+    where T: Rand
+{
+    fn generate(&self, _: &GeneContext, rng: &mut XorShiftRng) -> T {
+        rng.gen::<T>()
+    }
+}
+
+/// Create genenumber genes with the generator
+pub fn generate_genes<T>(generator: &dyn GeneGenerator<T>,
+                         genenumber: usize,
+                         rng: &mut XorShiftRng)
+                         -> Vec<T> {
+    (0..genenumber)
+        .map(|position| generator.generate(&GeneContext { position, genenumber }, rng))
+        .collect()
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+extern crate rand;
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+use generator::*;
+use genetic::*;
+use mutation::{GeneInsertionMutation, Mutation, RandomResetting};
+
+/// Gene which does not implement Rand
+#[derive(Debug,Clone,PartialEq)]
+struct Step {
+    position: usize,
+    value: u8,
+}
+
+#[allow(dead_code)]
+fn step(context: &GeneContext, rng: &mut XorShiftRng) -> Step {
+    Step {
+        position: context.position,
+        value: rng.gen_range(0, 4),
+    }
+}
+
+#[allow(dead_code)]
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn generate_with_context() {
+    let genes = generate_genes(&step, 5, &mut rng());
+    let positions: Vec<usize> = genes.iter().map(|g| g.position).collect();
+    assert_eq!(positions, vec![0, 1, 2, 3, 4]);
+    let i = Individual::new_with_generator(3, &step, &mut rng());
+    let i = i.bound_length(6, 8, &step, &mut rng());
+    assert_eq!(i.genes.len(), 6);
+    assert!(i.genes.iter().enumerate().all(|(n, g)| g.position == n));
+    let numbers: Vec<u8> = generate_genes(&RandomGenes, 4, &mut rng());
+    assert_eq!(numbers.len(), 4);
+}
+
+#[test]
+fn mutations_use_the_generator() {
+    let zero = |c: &GeneContext, _: &mut XorShiftRng| {
+        Step {
+            position: c.position,
+            value: 9,
+        }
+    };
+    let i = Individual::new_with_generator(4, &step, &mut rng());
+    let m = RandomResetting.mutate(&i, 1f32, &zero, &mut rng());
+    assert!(m.genes.iter().enumerate().all(|(n, g)| g.value == 9 && g.position == n));
+    let m = GeneInsertionMutation.mutate(&i, 1f32, &zero, &mut rng());
+    assert_eq!(m.genes.iter().filter(|g| g.value == 9).count(), 1);
+}
+
+#[test]
+fn population_with_generator() {
+    let fitness = |i: &Individual<Step>| i.genes.iter().map(|g| g.value as i32).sum::<i32>();
+    let c = PopulationConfiguration::builder_with_generator(fitness, step)
+        .population_size(16)
+        .genenumber(4)
+        .mutation_rate(0.3f32)
+        .threadpool_size(2)
+        .seed(5)
+        .build()
+        .unwrap();
+    let mut p = Population::new(c).unwrap();
+    for _ in 0..5 {
+        p = p.evolve();
+    }
+    assert_eq!(p.individuals_and_scores.len(), 16);
+    assert!(p.individuals_and_scores
+        .iter()
+        .all(|a| a.0.genes.iter().enumerate().all(|(n, g)| g.position == n && g.value < 4)));
+}
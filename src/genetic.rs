@@ -1,11 +1,30 @@
 extern crate rand;
-use rand::{Rand, Rng};
+use rand::{Rand, Rng, SeedableRng, XorShiftRng};
 
 extern crate threadpool;
 use threadpool::ThreadPool;
 
+use std::sync::Arc;
 use std::sync::mpsc::channel;
-use std::cmp::PartialEq;
+use std::time::{Duration, Instant};
+use std::cmp::{Ordering, PartialEq};
+use std::hash::Hash;
+
+use selection::Selection;
+use crossover::{Crossover, UniformCrossover};
+use mutation::{Mutation, RandomResetting};
+use cache::{CacheStatistics, FitnessCache};
+use pareto::{Objectives, non_dominated_sort, rank_and_crowding, crowded_comparison, select_best};
+use score::{Direction, Score};
+use replacement::{Replacement, Victim};
+use adaptation::{Adaptation, Feedback};
+use niching::{Distance, Niching, share};
+use restart::Restart;
+use memetic::{Inheritance, Memetic, SearchContext};
+use statistics::{Statistics, unique_fraction, unique_genomes};
+use observer::{Control, Event, Observer};
+use generator::{GeneContext, GeneGenerator, RandomGenes, generate_genes};
+use error::{ConfigurationError, Error, check, check_rate};
 
 
 /// Rappresent a candidate solution for the problem
@@ -15,15 +34,15 @@ pub struct Individual<T: 'static> {
 }
 
 impl<T> Individual<T>
-    where T: Clone + Rand + Send + Sync + PartialEq + 'static
+    where T: Clone + Send + Sync + PartialEq + 'static
 {
-    /// Create a new individual which contains a vector of genenumber of random initiliazed T
-    pub fn new(genenumber: usize) -> Individual<T> {
-        let mut vec: Vec<T> = Vec::new();
-        for _ in 0..genenumber {
-            vec.push(rand::random::<T>());
-        }
-        Individual { genes: vec.clone() }
+    /// Create a new individual which contains a vector of genenumber of T
+    /// created by the generator
+    pub fn new_with_generator(genenumber: usize,
+                              generator: &dyn GeneGenerator<T>,
+                              rng: &mut XorShiftRng)
+                              -> Individual<T> {
+        Individual { genes: generate_genes(generator, genenumber, rng) }
     }
 
     /// Create a new individual from a vector of T
@@ -31,154 +50,897 @@ impl<T> Individual<T>
         Individual { genes: v.clone() }
     }
 
+    /// Return an Individual<T> with at least min_genenumber and at most max_genenumber
+    /// genes, the missing genes are created by the generator
+    pub fn bound_length(&self,
+                        min_genenumber: usize,
+                        max_genenumber: usize,
+                        generator: &dyn GeneGenerator<T>,
+                        rng: &mut XorShiftRng)
+                        -> Individual<T> {
+        let mut genes = self.genes.clone();
+        genes.truncate(max_genenumber);
+        while genes.len() < min_genenumber {
+            let context = GeneContext {
+                position: genes.len(),
+                genenumber: min_genenumber,
+            };
+            genes.push(generator.generate(&context, rng));
+        }
+        Individual { genes }
+    }
+
     /// Return an Individual<T> which is the result of the crossover operation
     /// between self and the second Individual<T>, accordingly the uniform_rate parameter
     pub fn crossover(&self, i2: Individual<T>, uniform_rate: f32) -> Individual<T> {
-        let i1 = self.clone();
-        let mut v: Vec<T> = Vec::new();
-        let len = if i1.genes.len() < i2.genes.len() {
-            i1.genes.len()
-        } else {
-            i2.genes.len()
-        };
-        let mut rng = rand::thread_rng();
-        for i in 0..len {
-            if rng.gen_range(0f32, 1f32) < uniform_rate {
-                v.push(i1.genes.get(i).unwrap().clone());
-            } else {
-                v.push(i2.genes.get(i).unwrap().clone());
-            }
-        }
-        Individual::new_with_vec(v)
+        UniformCrossover.crossover(self, &i2, uniform_rate, &mut rand::weak_rng()).remove(0)
+    }
+
+    /// Return an Individual<T> which is the result of the mutate operation, accordingly
+    /// the mutation_rate parameter, the new genes are created by the generator
+    pub fn mutate_with_generator(&self,
+                                 mutation_rate: f32,
+                                 generator: &dyn GeneGenerator<T>)
+                                 -> Individual<T> {
+        RandomResetting.mutate(self, mutation_rate, generator, &mut rand::weak_rng())
+    }
+}
+
+impl<T> Individual<T>
+    where T: Clone + Rand + Send + Sync + PartialEq + 'static
+{
+    /// Create a new individual which contains a vector of genenumber of random initiliazed T
+    pub fn new(genenumber: usize) -> Individual<T> {
+        Individual::new_with_rng(genenumber, &mut rand::weak_rng())
+    }
+
+    /// Create a new individual which contains a vector of genenumber of T
+    /// initiliazed by the random number generator
+    pub fn new_with_rng(genenumber: usize, rng: &mut XorShiftRng) -> Individual<T> {
+        Individual::new_with_generator(genenumber, &RandomGenes, rng)
     }
 
-    /// Return an Individual<T> which is the result of the mutate operation, accordingly the mutation_rate parameter
+    /// Return an Individual<T> which is the result of the mutate operation, accordingly
+    /// the mutation_rate parameter
     pub fn mutate(&self, mutation_rate: f32) -> Individual<T> {
-        let i = self.clone();
-        let mut v: Vec<T> = Vec::new();
-        let mut rng = rand::thread_rng();
-        for x in i.genes.iter() {
-            if rng.gen_range(0f32, 1f32) < mutation_rate {
-                v.push(rand::random::<T>());
-            } else {
-                v.push(x.clone());
-            }
-        }
-        Individual::new_with_vec(v)
+        self.mutate_with_generator(mutation_rate, &RandomGenes)
     }
 }
+
+/// Function used to calculate the score of an Individual, it can capture
+/// any context (problem instance, lookup tables, caches) and it is shared
+/// between the threads of the pool
+pub type Fitness<T, S = i32> = Arc<dyn Fn(&Individual<T>) -> S + Send + Sync>;
+
+/// Function used to calculate the scores of many Individuals at once, like a simulator
+/// which shares its setup between them. It has to return a score for each Individual,
+/// in the same order, otherwise the evolution panics
+pub type BatchFitness<T, S = i32> = Arc<dyn Fn(&[Individual<T>]) -> Vec<S> + Send + Sync>;
+
+/// Get a BatchFitness which calls the fitness for each Individual
+pub fn batch_fitness<T: 'static, S: 'static>(fitness: Fitness<T, S>) -> BatchFitness<T, S> {
+    Arc::new(move |individuals: &[Individual<T>]| individuals.iter().map(|i| fitness(i)).collect())
+}
+
+/// Stream of the seed of a generation used to replace the duplicates, the
+/// stream 0 selects the parents and the streams from 1 create the offsprings
+const DUPLICATES_STREAM: u64 = u64::MAX;
+
+/// Mixed with the seed of a generation to create the immigrants of the Restart
+const IMMIGRANTS_SEED: u64 = 0x5851_F42D_4C95_7F2D;
+
+/// Create the random number generator of a stream of the seed,
+/// different streams of the same seed are independent
+pub fn seeded_rng(seed: u64, stream: u64) -> XorShiftRng {
+    let mut state = seed ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+    let (a, b) = (next(), next());
+    XorShiftRng::from_seed([a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32 | 1])
+}
+
 /// A set of Individuals
 #[derive(Clone)]
-pub struct Population<T: 'static> {
+pub struct Population<T: 'static, S: 'static = i32> {
     /// Contains set of Individual and the relative score
-    pub individuals_and_scores: Vec<(Individual<T>, i32)>,
+    pub individuals_and_scores: Vec<(Individual<T>, S)>,
     /// Contains the configurations used to create the Population
-    pub configuration: PopulationConfiguration<T>,
+    pub configuration: PopulationConfiguration<T, S>,
     /// Rappresent the generation of the Population
     pub generation: usize,
+    /// Number of Individuals evaluated since the creation of the Population
+    pub evaluations: usize,
+    /// Number of generations without a better Individual
+    pub stagnation: usize,
+    /// True if an Observer has asked to stop during the creation of the Population
+    pub stop_requested: bool,
+    /// Wall time used to create the Population
+    elapsed: Duration,
+    /// Threads used to create the next generations
+    pool: ThreadPool,
+    /// Objectives calculated by the multi objective evolution and the genomes
+    /// they belong to, in the order of the Individuals
+    objective_values: Vec<(Individual<T>, Vec<f64>)>,
 }
 
 /// Rappresent the configuration associated to a Population
 #[derive(Clone)]
-pub struct PopulationConfiguration<T: 'static> {
+pub struct PopulationConfiguration<T: 'static, S: 'static = i32> {
     /// Fitness function used to calculate the score of an Individual
-    pub fitness: fn(Individual<T>) -> i32,
+    pub fitness: Fitness<T, S>,
+    /// Fitness function called once with all the new Individuals of a generation,
+    /// if None they are evaluated one by one by the fitness on the threads of the pool.
+    /// The fitness is still used for the Individuals evaluated alone, like the
+    /// ones of the local search and the replaced duplicates
+    pub batch_fitness: Option<BatchFitness<T, S>>,
+    /// Direction of the optimisation of the score, used by the selection,
+    /// the elitism and get_fittest
+    pub direction: Direction,
     /// Size of the Population
     pub population_size: usize,
-    /// Number of genes of each Individual
+    /// Creates the genes of the new Individuals and of the mutations
+    pub generator: Arc<dyn GeneGenerator<T>>,
+    /// Number of genes of each new Individual
     pub genenumber: usize,
+    /// Minimum number of genes of the offsprings
+    pub min_genenumber: usize,
+    /// Maximum number of genes of the offsprings
+    pub max_genenumber: usize,
+    /// Operator used to create the offsprings from the parents
+    pub crossover: Arc<dyn Crossover<T>>,
     /// Parameter used by the crossover function
     pub uniform_rate: f32,
+    /// Operator used to change the genes of the offsprings
+    pub mutation: Arc<dyn Mutation<T>>,
     /// Parameter used by the mutate function
     pub mutation_rate: f32,
-    /// Size of the set used to select the parent of the offsprings
-    pub tournmant_size: usize,
+    /// Local search applied to a fraction of the offsprings, if None
+    /// the offsprings are not improved
+    pub memetic: Option<Memetic<T, S>>,
+    /// Strategy used to select the parents of the offsprings
+    pub selection: Arc<dyn Selection>,
     /// Number of Individual to copy in the next generation
     pub elitism_size: usize,
+    /// Strategy used to build the next generation
+    pub replacement: Replacement,
+    /// Strategy used to keep different niches in the Population, if None
+    /// the Individuals compete only with the score
+    pub niching: Option<Niching<T>>,
+    /// If true the genomes which are already in the next generation are
+    /// replaced with new random Individuals
+    pub eliminate_duplicates: bool,
+    /// Strategy used to inject new Individuals when the best score stops
+    /// improving, if None the Population can stay on a local optimum
+    pub restart: Option<Restart>,
+    /// Schedule of the mutation_rate, if None the mutation_rate is fixed
+    pub mutation_adaptation: Option<Arc<dyn Adaptation>>,
+    /// Schedule of the uniform_rate, if None the uniform_rate is fixed
+    pub crossover_adaptation: Option<Arc<dyn Adaptation>>,
+    /// Observers notified after the creation of each generation
+    pub observers: Vec<Arc<dyn Observer>>,
     /// Number of thread used during the evolve function
     pub threadpool_size: usize,
+    /// Cache used to skip the fitness function for the genomes already evaluated,
+    /// if None each Individual is evaluated
+    pub cache: Option<Arc<dyn FitnessCache<T, S>>>,
+    /// Objectives used by the multi objective evolution, if None the
+    /// Population evolves using only the fitness
+    pub objectives: Option<Objectives<T>>,
+    /// Seed of the random number generators, with the same seed the
+    /// Population is the same for any number of threads.
+    /// If None the Population is different at each run
+    pub seed: Option<u64>,
 }
 
-impl<T> Population<T>
-    where T: Clone + Rand + Send + Sync + PartialEq + 'static
+impl<T, S> PopulationConfiguration<T, S> {
+    /// Verify the configuration, the error contains every problem found
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut problems = Vec::new();
+        if self.population_size == 0 {
+            problems.push(ConfigurationError::EmptyPopulation);
+        }
+        if self.elitism_size > self.population_size {
+            problems.push(ConfigurationError::ElitismTooLarge {
+                elitism_size: self.elitism_size,
+                population_size: self.population_size,
+            });
+        }
+        if self.genenumber < self.min_genenumber || self.genenumber > self.max_genenumber {
+            problems.push(ConfigurationError::InvalidGeneNumber {
+                genenumber: self.genenumber,
+                min_genenumber: self.min_genenumber,
+                max_genenumber: self.max_genenumber,
+            });
+        }
+        let too_few = match self.replacement {
+            Replacement::MuCommaLambda { offsprings } if offsprings < self.population_size => {
+                Some(offsprings)
+            }
+            Replacement::SteadyState { offsprings: 0, .. } |
+            Replacement::MuPlusLambda { offsprings: 0 } => Some(0),
+            _ => None,
+        };
+        if let Some(offsprings) = too_few {
+            problems.push(ConfigurationError::TooFewOffsprings {
+                offsprings,
+                population_size: self.population_size,
+            });
+        }
+        problems.extend(self.selection.validate().err());
+        problems.extend(self.mutation.validate().err());
+        problems.extend(check_rate("mutation_rate", self.mutation_rate));
+        problems.extend(check_rate("uniform_rate", self.uniform_rate));
+        problems.extend(self.restart.and_then(|r| r.check()));
+        if let Some(ref memetic) = self.memetic {
+            problems.extend(check_rate("local search rate", memetic.rate));
+        }
+        check(problems)
+    }
+}
+
+/// Offsprings created by an evolution, the number of them which are better
+/// than both their parents and the number of Individuals evaluated
+struct Offsprings<T: 'static, S> {
+    individuals: Vec<(Individual<T>, S)>,
+    successes: usize,
+    evaluations: usize,
+    /// Objectives of the next generation, if the evolution has calculated them
+    objective_values: Vec<(Individual<T>, Vec<f64>)>,
+    /// Random Individuals evaluated with the offsprings, used by the Restart
+    /// if the next generation stagnates
+    immigrants: Vec<(Individual<T>, S)>,
+}
+
+/// Individuals of the next generation and the offsprings created for it
+type NextGeneration<T, S> = (Vec<(Individual<T>, S)>, Offsprings<T, S>);
+
+/// Execute job for each number in 0..jobs on the threads of the pool, the jobs are
+/// split in a batch for each thread and the results are in the order of the jobs
+fn execute_batches<R, F>(pool: &ThreadPool, jobs: usize, job: F) -> Vec<R>
+    where R: Send + 'static,
+          F: Fn(usize) -> R + Send + Sync + 'static
+{
+    let job = Arc::new(job);
+    let batch = jobs.div_ceil(pool.max_count()).max(1);
+    let (tx, rx) = channel();
+    let mut batches = 0;
+    for start in (0..jobs).step_by(batch) {
+        let tx = tx.clone();
+        let job = job.clone();
+        let end = (start + batch).min(jobs);
+        pool.execute(move || {
+            let results: Vec<R> = (start..end).map(|j| job(j)).collect();
+            tx.send((start, results)).unwrap();
+        });
+        batches += 1;
+    }
+    drop(tx);
+    let mut results = rx.iter().collect::<Vec<_>>();
+    if results.len() < batches {
+        panic!("a job panicked in the threadpool");
+    }
+    results.sort_by_key(|a| a.0);
+    results.into_iter().flat_map(|a| a.1).collect()
+}
+
+/// Calculate the score of the Individual, using the cache of the configuration
+fn evaluate<T, S: Clone>(configuration: &PopulationConfiguration<T, S>, i: &Individual<T>) -> S {
+    match configuration.cache {
+        Some(ref cache) => {
+            cache.get(i).unwrap_or_else(|| {
+                let score = (configuration.fitness)(i);
+                cache.insert(i, score.clone());
+                score
+            })
+        }
+        None => (configuration.fitness)(i),
+    }
+}
+
+/// Score of an Individual evaluated by evaluate_all
+enum Lookup<S> {
+    /// The score was in the cache
+    Cached(S),
+    /// The genome is the one of the missing Individual at the index
+    Missing(usize),
+    /// The genome is equal to the one of a previous missing Individual
+    Duplicate(usize),
+}
+
+/// Calculate the scores of the individuals, using the cache of the configuration.
+/// The genomes which are not in the cache are evaluated once, even if some
+/// individuals share them, with a single call of the batch_fitness, if any,
+/// otherwise in parallel with the fitness. Without a cache each individual is evaluated
+fn evaluate_all<T, S>(configuration: &Arc<PopulationConfiguration<T, S>>,
+                      pool: &ThreadPool,
+                      individuals: Vec<Individual<T>>)
+                      -> Vec<(Individual<T>, S)>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Clone + Send + 'static
+{
+    let mut missing: Vec<Individual<T>> = Vec::new();
+    let mut lookups: Vec<Lookup<S>> = Vec::with_capacity(individuals.len());
+    let first = configuration.cache.as_ref().map(|c| c.first_occurrences(&individuals));
+    for (n, i) in individuals.iter().enumerate() {
+        let score = match (&configuration.cache, &first) {
+            (Some(cache), Some(first)) => {
+                if let Some(&Lookup::Missing(m)) = lookups.get(first[n]) {
+                    lookups.push(Lookup::Duplicate(m));
+                    continue;
+                }
+                cache.get(i)
+            }
+            _ => None,
+        };
+        lookups.push(match score {
+            Some(score) => Lookup::Cached(score),
+            None => {
+                missing.push(i.clone());
+                Lookup::Missing(missing.len() - 1)
+            }
+        });
+    }
+    let expected = missing.len();
+    let missing = Arc::new(missing);
+    let scores = match configuration.batch_fitness {
+        Some(ref batch) => batch(&missing),
+        None => {
+            let shared_configuration = configuration.clone();
+            let shared_missing = missing.clone();
+            execute_batches(pool,
+                            expected,
+                            move |n| (shared_configuration.fitness)(&shared_missing[n]))
+        }
+    };
+    if scores.len() != expected {
+        panic!("the batch fitness returned {} scores for {} individuals",
+               scores.len(),
+               expected);
+    }
+    if let Some(ref cache) = configuration.cache {
+        for (i, score) in missing.iter().zip(scores.iter()) {
+            cache.insert(i, score.clone());
+        }
+    }
+    individuals.into_iter()
+        .zip(lookups)
+        .map(|(i, lookup)| {
+            let score = match lookup {
+                Lookup::Cached(score) => score,
+                Lookup::Missing(m) => scores[m].clone(),
+                Lookup::Duplicate(m) => {
+                    // the lookup counts the hit, the score can be already removed
+                    let cached = configuration.cache.as_ref().and_then(|c| c.get(&i));
+                    cached.unwrap_or_else(|| scores[m].clone())
+                }
+            };
+            (i, score)
+        })
+        .collect()
+}
+
+/// Improve the offspring i, which has the score f, with the local search
+/// of memetic. Return the offspring kept and the number of evaluations
+/// of the local search
+fn improve<T, S>(configuration: &PopulationConfiguration<T, S>,
+                 memetic: &Memetic<T, S>,
+                 i: Individual<T>,
+                 f: S,
+                 rng: &mut XorShiftRng)
+                 -> ((Individual<T>, S), usize)
+    where T: Clone,
+          S: Clone
+{
+    let fitness = |i: &Individual<T>| evaluate(configuration, i);
+    let mut context = SearchContext::new(&fitness,
+                                         &*configuration.generator,
+                                         configuration.direction,
+                                         memetic.budget);
+    let (improved, score) = memetic.local_search.improve(&i, &f, &mut context, rng);
+    let evaluations = context.evaluations();
+    match memetic.inheritance {
+        Inheritance::Lamarckian => ((improved, score), evaluations),
+        Inheritance::Baldwinian => ((i, score), evaluations),
+    }
+}
+
+/// Get the seed of the random number generators of a generation
+fn generation_seed<T, S>(configuration: &PopulationConfiguration<T, S>, generation: usize) -> u64 {
+    match configuration.seed {
+        Some(seed) => seed ^ (generation as u64).wrapping_mul(0xD1B5_4A32_D192_ED03),
+        None => rand::thread_rng().gen(),
+    }
+}
+
+impl<T, S> Population<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
 {
     /// Create a new Population from a vector of individuals,
-    /// a configuration and the number of the generation
-    pub fn new_with_vec(vec: Vec<(Individual<T>, i32)>,
-                        configuration: PopulationConfiguration<T>,
+    /// a configuration and the number of the generation.
+    /// Return an error if the configuration is not valid
+    pub fn new_with_vec(vec: Vec<(Individual<T>, S)>,
+                        configuration: PopulationConfiguration<T, S>,
                         generation: usize)
-                        -> Population<T> {
-        Population {
+                        -> Result<Population<T, S>, Error> {
+        configuration.validate()?;
+        let pool = ThreadPool::new(configuration.threadpool_size.max(1));
+        Ok(Population {
             individuals_and_scores: vec,
-            configuration: configuration,
-            generation: generation,
-        }
+            configuration,
+            generation,
+            evaluations: 0,
+            stagnation: 0,
+            stop_requested: false,
+            elapsed: Duration::new(0, 0),
+            pool,
+            objective_values: Vec::new(),
+        })
+    }
+
+    /// Create a new random generation accordingly the configuration,
+    /// the individuals are evaluated in parallel.
+    /// Return an error if the configuration is not valid
+    pub fn new(configuration: PopulationConfiguration<T, S>) -> Result<Population<T, S>, Error> {
+        let start = Instant::now();
+        let seed = generation_seed(&configuration, 0);
+        let mut p = Population::new_with_vec(Vec::new(), configuration, 0)?;
+        p.individuals_and_scores = p.new_individuals(p.configuration.population_size, seed);
+        p.evaluations = p.individuals_and_scores.len();
+        p.elapsed = start.elapsed();
+        Ok(p)
     }
 
-    /// Create a new random generation accordingly the configuration
-    pub fn new(configuration: PopulationConfiguration<T>) -> Population<T> {
-        let mut v = Vec::<(Individual<T>, i32)>::new();
-        for _ in 0..configuration.population_size {
-            let i = Individual::<T>::new(configuration.genenumber);
-            let score = (configuration.fitness)(i.clone());
-            v.push((i, score));
+    /// Create in parallel number new Individuals with the generator of the
+    /// configuration and evaluate them, the Individuals are the same for any
+    /// number of threads
+    fn new_individuals(&self, number: usize, seed: u64) -> Vec<(Individual<T>, S)> {
+        let configuration = Arc::new(self.configuration.clone());
+        evaluate_all(&configuration, &self.pool, self.new_genomes(number, seed))
+    }
+
+    /// Create in parallel number new Individuals with the generator of the
+    /// configuration, without evaluating them
+    fn new_genomes(&self, number: usize, seed: u64) -> Vec<Individual<T>> {
+        let configuration = Arc::new(self.configuration.clone());
+        execute_batches(&self.pool, number, move |n| {
+            let mut rng = seeded_rng(seed, n as u64 + 1);
+            Individual::new_with_generator(configuration.genenumber,
+                                           &*configuration.generator,
+                                           &mut rng)
+        })
+    }
+
+    /// Get the number of Individuals the Restart of the configuration replaces
+    /// if the next generation does not improve the best score
+    fn immigrants(&self) -> usize {
+        let size = self.individuals_and_scores.len();
+        let elites = self.configuration.elitism_size.min(size);
+        self.configuration
+            .restart
+            .map_or(0, |restart| restart.replaced(self.stagnation + 1, size, elites))
+    }
+
+    /// Replace Individuals with the immigrants, evaluated with the offsprings, if the
+    /// configuration has a Restart and the Population has stagnated. New Individuals
+    /// are created if the immigrants are not enough. Return true if a catastrophe happened
+    fn restart(&mut self, mut immigrants: Vec<(Individual<T>, S)>) -> bool {
+        let restart = match self.configuration.restart {
+            Some(restart) => restart,
+            None => return false,
+        };
+        let size = self.individuals_and_scores.len();
+        let elites = self.configuration.elitism_size.min(size);
+        let replaced = restart.replaced(self.stagnation, size, elites);
+        if replaced == 0 {
+            return false;
         }
-        Population::new_with_vec(v, configuration, 0)
+        let (mut v, catastrophe) = match restart {
+            Restart::RandomImmigrants { .. } => {
+                let direction = self.configuration.direction;
+                let mut v = self.individuals_and_scores.clone();
+                v.sort_by(|a, b| direction.compare(&b.1, &a.1));
+                v.truncate(size - replaced);
+                (v, false)
+            }
+            Restart::Catastrophe { .. } => (self.get_top(size - replaced), true),
+        };
+        let missing = size - v.len();
+        immigrants.truncate(missing);
+        if immigrants.len() < missing {
+            let seed = generation_seed(&self.configuration, self.generation) ^ IMMIGRANTS_SEED;
+            let created = self.new_individuals(missing - immigrants.len(), seed);
+            self.evaluations += created.len();
+            immigrants.extend(created);
+        }
+        v.extend(immigrants);
+        self.individuals_and_scores = v;
+        if catastrophe {
+            self.stagnation = 0;
+        }
+        catastrophe
     }
 
     /// Get the Individual and the relative score of the Individual 
-    /// with the highest score
-    pub fn get_fittest(&self) -> Option<(Individual<T>, i32)> {
-        let individuals = self.individuals_and_scores.clone();
-        let opt = individuals.iter().max_by_key(|a| a.1);
-        if opt.is_some() {
-            Some(opt.unwrap().clone())
-        } else {
-            None
-        }
+    /// with the best score, accordingly the direction of the configuration
+    pub fn get_fittest(&self) -> Option<(Individual<T>, S)> {
+        let direction = self.configuration.direction;
+        self.individuals_and_scores.iter().max_by(|a, b| direction.compare(&a.1, &b.1)).cloned()
     }
 
-    /// Get the Individuals with the highest score 
-    fn get_top(&self, number: usize) -> Vec<(Individual<T>, i32)> {
-        let mut v: Vec<(Individual<T>, i32)> = Vec::new();
-        let iter = self.individuals_and_scores.iter().clone();
+    /// Get the number of hits and misses of the cache of the configuration
+    pub fn get_cache_statistics(&self) -> Option<CacheStatistics> {
+        self.configuration.cache.as_ref().map(|c| c.statistics())
+    }
+
+    /// Get the number Individuals with the best score, from the best one, without
+    /// duplicates when the Population has enough different Individuals
+    pub fn get_top(&self, number: usize) -> Vec<(Individual<T>, S)> {
         if self.individuals_and_scores.len() <= number {
-            self.individuals_and_scores.clone();
+            return self.individuals_and_scores.clone();
+        }
+        let direction = self.configuration.direction;
+        let mut order: Vec<&(Individual<T>, S)> = self.individuals_and_scores.iter().collect();
+        order.sort_by(|a, b| direction.compare(&b.1, &a.1));
+        let mut v: Vec<(Individual<T>, S)> = Vec::new();
+        let mut duplicates: Vec<&(Individual<T>, S)> = Vec::new();
+        for is in order {
+            if v.len() == number {
+                break;
+            }
+            if v.iter().any(|a| a.0 == is.0) {
+                duplicates.push(is);
+            } else {
+                v.push(is.clone());
+            }
+        }
+        let missing = number - v.len();
+        v.extend(duplicates.into_iter().take(missing).cloned());
+        v.sort_by(|a, b| direction.compare(&b.1, &a.1));
+        v
+    }
+
+    /// Create a new Population where the worst Individuals are replaced by
+    /// individuals, which are evaluated with the fitness of the configuration
+    pub fn replace_worst(&self, mut individuals: Vec<Individual<T>>) -> Population<T, S> {
+        let direction = self.configuration.direction;
+        let mut order: Vec<usize> = (0..self.individuals_and_scores.len()).collect();
+        order.sort_by(|a, b| {
+            direction.compare(&self.individuals_and_scores[*a].1,
+                              &self.individuals_and_scores[*b].1)
+        });
+        individuals.truncate(order.len());
+        let configuration = Arc::new(self.configuration.clone());
+        let evaluated = evaluate_all(&configuration, &self.pool, individuals);
+        let evaluations = self.evaluations + evaluated.len();
+        let mut v = self.individuals_and_scores.clone();
+        for (position, i) in order.into_iter().zip(evaluated) {
+            v[position] = i;
+        }
+        Population {
+            individuals_and_scores: v,
+            configuration: self.configuration.clone(),
+            generation: self.generation,
+            evaluations,
+            stagnation: self.stagnation,
+            stop_requested: self.stop_requested,
+            elapsed: self.elapsed,
+            pool: self.pool.clone(),
+            objective_values: self.objective_values.clone(),
+        }
+    }
+
+    /// Get the scores of the individuals, as used by the Selection
+    fn get_scores(&self) -> Vec<f64> {
+        let direction = self.configuration.direction;
+        self.individuals_and_scores.iter().map(|a| direction.selection_value(&a.1)).collect()
+    }
+
+    /// Get the indexes of the parents of the offsprings, accordingly
+    /// the selection of the configuration
+    fn select_parents(&self, number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        let mut scores = self.get_scores();
+        if let Some(Niching::FitnessSharing { radius, alpha, ref distance }) =
+               self.configuration.niching {
+            let individuals: Vec<&Individual<T>> =
+                self.individuals_and_scores.iter().map(|a| &a.0).collect();
+            scores = share(&scores, &individuals, radius, alpha, distance);
+        }
+        self.configuration.selection.select(&scores, number, rng)
+    }
+
+    /// Replace the genomes of v which are equal to a previous genome with new
+    /// random Individuals, if the configuration requires it. Return the number
+    /// of new Individuals. The new Individuals use their own stream of the seed
+    fn replace_duplicates(&self, v: &mut [(Individual<T>, S)], seed: u64) -> usize {
+        let mut evaluations = 0;
+        if !self.configuration.eliminate_duplicates {
+            return evaluations;
+        }
+        let rng = &mut seeded_rng(seed, DUPLICATES_STREAM);
+        for n in 1..v.len() {
+            let mut attempts = 0;
+            while attempts < 10 && v[..n].iter().any(|a| a.0 == v[n].0) {
+                let i = Individual::new_with_generator(self.configuration.genenumber,
+                                                       &*self.configuration.generator,
+                                                       rng);
+                let score = evaluate(&self.configuration, &i);
+                v[n] = (i, score);
+                attempts += 1;
+                evaluations += 1;
+            }
+        }
+        evaluations
+    }
+
+    /// Get the threads used to create the next generation, the threads are
+    /// the same between the generations while threadpool_size does not change
+    fn get_pool(&self) -> ThreadPool {
+        if self.pool.max_count() == self.configuration.threadpool_size.max(1) {
+            self.pool.clone()
         } else {
-            let first_max = iter.max_by_key(|a| a.1);
-            v.push(first_max.unwrap().clone());
-            for _ in 1..number {
-                let mut max: Option<(Individual<T>, i32)> = None;
-                for is in self.individuals_and_scores.clone() {
-                    if max.clone().is_none() {
-                        max = Some(is);
-                    } else if max.clone().unwrap().1 < is.1 && v.iter().all(|a| a.0 != is.0) {
-                        max = Some(is);
+            ThreadPool::new(self.configuration.threadpool_size.max(1))
+        }
+    }
+
+    /// Create offsprings new Individuals from the parents, each pair of
+    /// parents is used by the crossover. The offsprings are placed in the
+    /// same order whatever thread creates them, the local search of the
+    /// configuration improves a fraction of them
+    fn breed(&self, parents: Vec<usize>, offsprings: usize, seed: u64) -> Offsprings<T, S> {
+        let children = self.configuration.crossover.children().max(1);
+        let matings = offsprings.div_ceil(children).min(parents.len() / 2);
+        let parents = Arc::new(parents);
+        let shared_parents = parents.clone();
+        let individuals = Arc::new(self.individuals_and_scores.clone());
+        let shared_individuals = individuals.clone();
+        let configuration = Arc::new(self.configuration.clone());
+        let shared_configuration = configuration.clone();
+        let pool = self.get_pool();
+        let created = execute_batches(&pool, matings, move |m| {
+            let configuration = &shared_configuration;
+            let mut rng = seeded_rng(seed, m as u64 + 1);
+            let i1 = &shared_individuals[shared_parents[2 * m]].0;
+            let i2 = &shared_individuals[shared_parents[2 * m + 1]].0;
+            let needed = children.min(offsprings - m * children);
+            configuration.crossover
+                .crossover(i1, i2, configuration.uniform_rate, &mut rng)
+                .into_iter()
+                .take(needed)
+                .map(|ic| {
+                    let generator = &*configuration.generator;
+                    let im = configuration.mutation
+                        .mutate(&ic, configuration.mutation_rate, generator, &mut rng)
+                        .bound_length(configuration.min_genenumber,
+                                      configuration.max_genenumber,
+                                      generator,
+                                      &mut rng);
+                    // the seed of the local search, if the offspring has to be improved
+                    let search = match configuration.memetic {
+                        Some(ref memetic) if rng.next_f32() < memetic.rate => Some(rng.next_u64()),
+                        _ => None,
+                    };
+                    (m, im, search)
+                })
+                .collect::<Vec<_>>()
+        });
+        let created: Vec<(usize, Individual<T>, Option<u64>)> =
+            created.into_iter().flatten().collect();
+        let mut genomes: Vec<Individual<T>> = created.iter().map(|c| c.1.clone()).collect();
+        genomes.extend(self.new_genomes(self.immigrants(), seed ^ IMMIGRANTS_SEED));
+        let mut evaluated = evaluate_all(&configuration, &pool, genomes);
+        let immigrants = evaluated.split_off(created.len());
+        let mut evaluations = evaluated.len() + immigrants.len();
+        let evaluated = match self.configuration.memetic {
+            Some(ref memetic) => {
+                let searches: Vec<Option<u64>> = created.iter().map(|c| c.2).collect();
+                let memetic = memetic.clone();
+                let evaluated = Arc::new(evaluated);
+                let improved = execute_batches(&pool, searches.len(), move |n| {
+                    match searches[n] {
+                        Some(search) => {
+                            let (i, f) = evaluated[n].clone();
+                            let mut rng = seeded_rng(search, 0);
+                            improve(&configuration, &memetic, i, f, &mut rng)
+                        }
+                        None => (evaluated[n].clone(), 0),
+                    }
+                });
+                evaluations += improved.iter().map(|a| a.1).sum::<usize>();
+                improved.into_iter().map(|a| a.0).collect()
+            }
+            None => evaluated,
+        };
+        let direction = self.configuration.direction;
+        let successes = created.iter()
+            .zip(evaluated.iter())
+            .filter(|&(c, child)| {
+                let s1 = &individuals[parents[2 * c.0]].1;
+                let s2 = &individuals[parents[2 * c.0 + 1]].1;
+                let best = if direction.is_better(s1, s2) { s1 } else { s2 };
+                direction.is_better(&child.1, best)
+            })
+            .count();
+        Offsprings {
+            evaluations,
+            individuals: evaluated,
+            successes,
+            objective_values: Vec::new(),
+            immigrants,
+        }
+    }
+
+    /// Get the number of matings needed to create offsprings Individuals
+    fn matings(&self, offsprings: usize) -> usize {
+        offsprings.div_ceil(self.configuration.crossover.children().max(1))
+    }
+
+    /// Create a new Population from the current, using the crossover 
+    /// and mutation  operator. If the configuration has objectives
+    /// the multi objective evolution is used, otherwise the replacement
+    /// of the configuration. Then the Restart of the configuration, if any,
+    /// replaces some Individuals when the best score stops improving
+    pub fn evolve(&self) -> Population<T, S> {
+        let start = Instant::now();
+        if let Some(ref cache) = self.configuration.cache {
+            cache.next_generation();
+        }
+        let ((v, offsprings), generations) = match self.configuration.objectives {
+            Some(ref objectives) => (self.evolve_multi_objective(objectives), 1),
+            None => {
+                match (&self.configuration.niching, self.configuration.replacement) {
+                    (Some(Niching::DeterministicCrowding { distance }), _) => {
+                        (self.evolve_crowding(distance), 1)
+                    }
+                    (_, Replacement::Generational) => (self.evolve_generational(), 1),
+                    (_, Replacement::SteadyState { offsprings, victim }) => {
+                        (self.evolve_steady_state(offsprings, victim), offsprings)
+                    }
+                    (_, Replacement::MuPlusLambda { offsprings }) => {
+                        (self.evolve_truncation(offsprings, true), 1)
+                    }
+                    (_, Replacement::MuCommaLambda { offsprings }) => {
+                        (self.evolve_truncation(offsprings, false), 1)
                     }
-                }
-                if max.is_some() {
-                    v.push(max.unwrap());
                 }
             }
+        };
+        let mut p = Population {
+            individuals_and_scores: v,
+            configuration: self.configuration.clone(),
+            generation: self.generation + generations,
+            evaluations: self.evaluations + offsprings.evaluations,
+            stagnation: self.stagnation + 1,
+            stop_requested: false,
+            elapsed: Duration::new(0, 0),
+            pool: self.get_pool(),
+            objective_values: Vec::new(),
+        };
+        p.adapt_rates(&offsprings);
+        p.objective_values = offsprings.objective_values;
+        let direction = self.configuration.direction;
+        let improved = match (self.get_fittest(), p.get_fittest()) {
+            (Some(old), Some(new)) => direction.is_better(&new.1, &old.1),
+            (None, Some(_)) => true,
+            _ => false,
+        };
+        if improved {
+            p.stagnation = 0;
         }
-        v
+        let stagnation = p.stagnation;
+        let catastrophe = p.restart(offsprings.immigrants);
+        p.elapsed = start.elapsed();
+        if !p.configuration.observers.is_empty() {
+            let statistics = p.get_statistics();
+            let mut stop = p.notify(&Event::GenerationFinished(&statistics));
+            stop |= if improved {
+                p.notify(&Event::NewBest(&statistics))
+            } else {
+                p.notify(&Event::Stagnation(&statistics, stagnation))
+            };
+            if catastrophe {
+                stop |= p.notify(&Event::Restarted(&statistics));
+            }
+            p.stop_requested = stop;
+        }
+        p
+    }
+
+    /// Notify the event to all the Observers of the configuration,
+    /// return true if one of them has asked to stop
+    pub fn notify(&self, event: &Event) -> bool {
+        self.configuration
+            .observers
+            .iter()
+            .map(|o| o.notify(event))
+            .collect::<Vec<Control>>()
+            .contains(&Control::Stop)
     }
 
-    /// Get the Individual with the highest score from a random selection
-    /// of the individuals of the population
-    fn tournment(&self) -> Individual<T> {
-        let mut v: Vec<(Individual<T>, i32)> = Vec::new();
-        for _ in 0..self.configuration.tournmant_size {
-            let n = rand::thread_rng().gen_range(0, self.individuals_and_scores.len());
-            v.push(self.individuals_and_scores.get(n).unwrap().clone());
+    /// Get the Statistics of the generation, the rates are
+    /// the ones used to create the next generation
+    pub fn get_statistics(&self) -> Statistics {
+        Statistics {
+            generation: self.generation,
+            evaluations: self.evaluations,
+            elapsed: self.elapsed,
+            mutation_rate: self.configuration.mutation_rate,
+            uniform_rate: self.configuration.uniform_rate,
+            ..Statistics::new(&self.individuals_and_scores, self.configuration.direction)
         }
-        v.iter().max_by_key(|a| a.1).unwrap().0.clone()
     }
 
-    /// Create a new Population from the current, using the crossover 
-    /// and mutation  operator
-    pub fn evolve(&self) -> Population<T> {
-        let mut v: Vec<(Individual<T>, i32)> = Vec::new();
+    /// Update the rates of the configuration with its adaptations,
+    /// the new rates are used to create the next generation
+    fn adapt_rates(&mut self, offsprings: &Offsprings<T, S>) {
+        if self.configuration.mutation_adaptation.is_none() &&
+           self.configuration.crossover_adaptation.is_none() {
+            return;
+        }
+        let feedback = Feedback {
+            generation: self.generation,
+            success_rate: if offsprings.individuals.is_empty() {
+                0f64
+            } else {
+                offsprings.successes as f64 / offsprings.individuals.len() as f64
+            },
+            diversity: unique_fraction(&self.individuals_and_scores),
+        };
+        if let Some(adaptation) = self.configuration.mutation_adaptation.clone() {
+            self.configuration.mutation_rate =
+                adaptation.adapt(self.configuration.mutation_rate, &feedback);
+        }
+        if let Some(adaptation) = self.configuration.crossover_adaptation.clone() {
+            self.configuration.uniform_rate =
+                adaptation.adapt(self.configuration.uniform_rate, &feedback);
+        }
+    }
+
+    /// Get the index of the Individual of v replaced by an offspring
+    fn get_victim(&self, v: &[(Individual<T>, S)], victim: Victim, rng: &mut XorShiftRng) -> usize {
+        let direction = self.configuration.direction;
+        let worst = |a: &usize, b: &usize| direction.compare(&v[*b].1, &v[*a].1);
+        match victim {
+            Victim::Worst => (0..v.len()).max_by(worst).unwrap(),
+            Victim::TournamentLoser(size) => {
+                (0..size.max(1)).map(|_| rng.gen_range(0, v.len())).max_by(worst).unwrap()
+            }
+        }
+    }
+
+    /// Get the current Individuals where offsprings Individuals have
+    /// replaced the victims
+    fn evolve_steady_state(&self, offsprings: usize, victim: Victim) -> NextGeneration<T, S> {
+        let seed = generation_seed(&self.configuration, self.generation + 1);
+        let mut rng = seeded_rng(seed, 0);
+        let parents = self.select_parents(2 * self.matings(offsprings), &mut rng);
+        let mut v = self.individuals_and_scores.clone();
+        let children = self.breed(parents, offsprings, seed);
+        let direction = self.configuration.direction;
+        for child in &children.individuals {
+            if v.is_empty() {
+                break;
+            }
+            if self.configuration.eliminate_duplicates && v.iter().any(|a| a.0 == child.0) {
+                continue;
+            }
+            let position = self.get_victim(&v, victim, &mut rng);
+            if !direction.is_better(&v[position].1, &child.1) {
+                v[position] = child.clone();
+            }
+        }
+        (v, children)
+    }
+
+    /// Get the next generation made of the elites and the offsprings
+    fn evolve_generational(&self) -> NextGeneration<T, S> {
+        let seed = generation_seed(&self.configuration, self.generation + 1);
+        let mut v: Vec<(Individual<T>, S)> = Vec::new();
         let new_elitism_size = if self.configuration.elitism_size >
                                   self.configuration.population_size {
             self.configuration.population_size
@@ -188,27 +950,233 @@ impl<T> Population<T>
         for elite in self.get_top(new_elitism_size) {
             v.push(elite);
         }
-        let (tx, rx) = channel();
-        let pool = ThreadPool::new(if self.configuration.threadpool_size > 0 {
-            self.configuration.threadpool_size
-        } else {
-            1
-        });
-        for _ in new_elitism_size..self.configuration.population_size {
-            let tx = tx.clone();
-            let pop = (*self).clone();
-            pool.execute(move || {
-                let i1 = pop.tournment();
-                let i2 = pop.tournment();
-                let ic = i1.crossover(i2, pop.configuration.uniform_rate);
-                let im = ic.mutate(pop.configuration.mutation_rate);
-                let f = (pop.configuration.fitness)(im.clone());
-                tx.send((im, f)).unwrap();
-            });
+        let offsprings = self.configuration.population_size - new_elitism_size;
+        let parents = self.select_parents(2 * self.matings(offsprings), &mut seeded_rng(seed, 0));
+        let mut children = self.breed(parents, offsprings, seed);
+        v.extend(children.individuals.iter().cloned());
+        children.evaluations += self.replace_duplicates(&mut v, seed);
+        (v, children)
+    }
+
+    /// Get the next generation made of the best population_size Individuals
+    /// between the offsprings and, if plus, the current Individuals
+    fn evolve_truncation(&self, offsprings: usize, plus: bool) -> NextGeneration<T, S> {
+        let seed = generation_seed(&self.configuration, self.generation + 1);
+        let mut rng = seeded_rng(seed, 0);
+        let parents = self.select_parents(2 * self.matings(offsprings), &mut rng);
+        let mut children = self.breed(parents, offsprings, seed);
+        let mut v = if plus { self.individuals_and_scores.clone() } else { Vec::new() };
+        v.extend(children.individuals.iter().cloned());
+        let direction = self.configuration.direction;
+        v.sort_by(|a, b| direction.compare(&b.1, &a.1));
+        v.truncate(self.configuration.population_size);
+        children.evaluations += self.replace_duplicates(&mut v, seed);
+        (v, children)
+    }
+
+    /// Get the next generation with the deterministic crowding: the Individuals are
+    /// paired randomly, each pair creates two offsprings and each offspring competes
+    /// with the most similar parent
+    fn evolve_crowding(&self, distance: &Distance<T>) -> NextGeneration<T, S> {
+        let seed = generation_seed(&self.configuration, self.generation + 1);
+        let mut rng = seeded_rng(seed, 0);
+        let mut order: Vec<usize> = (0..self.individuals_and_scores.len()).collect();
+        rng.shuffle(&mut order);
+        let children_per_mating = self.configuration.crossover.children().max(1);
+        let matings_per_pair = 2usize.div_ceil(children_per_mating);
+        let mut parents: Vec<usize> = Vec::new();
+        for pair in order.chunks(2).filter(|pair| pair.len() == 2) {
+            for _ in 0..matings_per_pair {
+                parents.extend_from_slice(pair);
+            }
         }
-        for _ in new_elitism_size..self.configuration.population_size {
-            v.push(rx.recv().unwrap());
+        let group = matings_per_pair * children_per_mating;
+        let offsprings = parents.len() / 2 * children_per_mating;
+        let mut children = self.breed(parents, offsprings, seed);
+        let direction = self.configuration.direction;
+        let mut v = self.individuals_and_scores.clone();
+        for (pair, c) in order.chunks(2).zip(children.individuals.chunks(group)) {
+            if pair.len() < 2 || c.len() < 2 {
+                continue;
+            }
+            let p1 = &self.individuals_and_scores[pair[0]];
+            let p2 = &self.individuals_and_scores[pair[1]];
+            let straight = distance(&p1.0, &c[0].0) + distance(&p2.0, &c[1].0);
+            let crossed = distance(&p1.0, &c[1].0) + distance(&p2.0, &c[0].0);
+            let matches = if straight <= crossed {
+                [(pair[0], &c[0]), (pair[1], &c[1])]
+            } else {
+                [(pair[0], &c[1]), (pair[1], &c[0])]
+            };
+            for &(parent, child) in &matches {
+                if !direction.is_better(&v[parent].1, &child.1) {
+                    v[parent] = child.clone();
+                }
+            }
         }
-        Population::new_with_vec(v, self.configuration.clone(), self.generation + 1)
+        children.evaluations += self.replace_duplicates(&mut v, seed);
+        (v, children)
+    }
+
+    /// Get the next generation in the NSGA-II way: the parents are selected with a binary
+    /// tournament on rank and crowding distance, then the best population_size Individuals
+    /// between the parents and the offsprings survive. The objectives of the survivors
+    /// are kept for the next generation, every calculation counts as an evaluation
+    fn evolve_multi_objective(&self, objectives: &Objectives<T>) -> NextGeneration<T, S> {
+        let seed = generation_seed(&self.configuration, self.generation + 1);
+        let mut rng = seeded_rng(seed, 0);
+        let (mut values, calculated) = self.get_current_objectives(objectives);
+        let ranks = rank_and_crowding(&values);
+        let offsprings = self.configuration.population_size;
+        let matings = if ranks.is_empty() { 0 } else { self.matings(offsprings) };
+        let parents = (0..2 * matings)
+            .map(|_| {
+                let a = rng.gen_range(0, ranks.len());
+                let b = rng.gen_range(0, ranks.len());
+                if crowded_comparison(&ranks[a], &ranks[b]) == Ordering::Less { b } else { a }
+            })
+            .collect();
+        let mut children = self.breed(parents, offsprings, seed);
+        values.extend(self.get_objectives(objectives, &children.individuals));
+        children.evaluations += calculated + children.individuals.len();
+        let mut all: Vec<Option<(Individual<T>, S)>> = self.individuals_and_scores
+            .iter()
+            .chain(children.individuals.iter())
+            .cloned()
+            .map(Some)
+            .collect();
+        let survivors = select_best(&values, offsprings);
+        let mut v: Vec<(Individual<T>, S)> =
+            survivors.iter().map(|i| all[*i].take().unwrap()).collect();
+        children.evaluations += self.replace_duplicates(&mut v, seed);
+        children.objective_values = v.iter()
+            .zip(survivors)
+            .map(|(a, i)| (a.0.clone(), values[i].clone()))
+            .collect();
+        (v, children)
+    }
+
+    /// Get the objectives of the current Individuals, reusing the ones kept by the last
+    /// evolution for the same genomes. Return them and the number of calculated ones
+    fn get_current_objectives(&self, objectives: &Objectives<T>) -> (Vec<Vec<f64>>, usize) {
+        let known: Vec<Option<Vec<f64>>> = self.individuals_and_scores
+            .iter()
+            .enumerate()
+            .map(|(n, a)| match self.objective_values.get(n) {
+                Some(o) if o.0 == a.0 => Some(o.1.clone()),
+                _ => None,
+            })
+            .collect();
+        let missing: Vec<(Individual<T>, S)> = self.individuals_and_scores
+            .iter()
+            .zip(known.iter())
+            .filter(|&(_, k)| k.is_none())
+            .map(|(a, _)| a.clone())
+            .collect();
+        let mut calculated = self.get_objectives(objectives, &missing).into_iter();
+        let values = known.into_iter()
+            .map(|k| k.unwrap_or_else(|| calculated.next().unwrap()))
+            .collect();
+        (values, missing.len())
+    }
+
+    /// Calculate in parallel the objectives of the individuals
+    fn get_objectives(&self,
+                      objectives: &Objectives<T>,
+                      individuals: &[(Individual<T>, S)])
+                      -> Vec<Vec<f64>> {
+        let objectives = objectives.clone();
+        let individuals = Arc::new(individuals.to_vec());
+        execute_batches(&self.get_pool(),
+                        individuals.len(),
+                        move |i| objectives(&individuals[i].0))
+    }
+
+    /// Get the Individuals of the Pareto front and their objectives, the front
+    /// contains the Individuals which are not dominated by any other Individual.
+    /// If the configuration has no objectives the score is the only objective
+    pub fn get_pareto_front(&self) -> Vec<(Individual<T>, Vec<f64>)> {
+        let values = match self.configuration.objectives {
+            Some(ref objectives) => self.get_current_objectives(objectives).0,
+            None => self.get_scores().into_iter().map(|a| vec![a]).collect(),
+        };
+        match non_dominated_sort(&values).first() {
+            Some(front) => {
+                front.iter()
+                    .map(|i| (self.individuals_and_scores[*i].0.clone(), values[*i].clone()))
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+impl<T, S> Population<T, S>
+    where T: Clone + Send + Sync + Hash + Eq + 'static,
+          S: Score
+{
+    /// Get the Statistics of the generation with the statistics of the genomes
+    pub fn get_statistics_with_genomes(&self) -> Statistics {
+        self.get_statistics().with_genomes(&self.individuals_and_scores)
+    }
+
+    /// Get the number of different genomes in the Population
+    pub fn get_unique_genomes(&self) -> usize {
+        unique_genomes(&self.individuals_and_scores)
+    }
+}
+
+/// Something which evolves a set of Individuals, like a Population or an
+/// Archipelago, used by the planner to search with any of them
+pub trait Evolution<T, S>: Clone {
+    /// Create the next generation
+    fn evolve(&self) -> Self;
+    /// Get the Individual with the best score and its score
+    fn get_fittest(&self) -> Option<(Individual<T>, S)>;
+    /// Get the Statistics of the current generation
+    fn get_statistics(&self) -> Statistics;
+    /// Get the Statistics of the current generation with the statistics of the genomes
+    fn get_statistics_with_genomes(&self) -> Statistics
+        where T: Hash + Eq;
+    /// Notify the event to the Observers, return true if one of them has asked to stop
+    fn notify(&self, event: &Event) -> bool;
+    /// Verify if an Observer has asked to stop during the creation of the current generation
+    fn is_stop_requested(&self) -> bool;
+    /// Get the direction of the optimisation of the score
+    fn get_direction(&self) -> Direction;
+}
+
+impl<T, S> Evolution<T, S> for Population<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    fn evolve(&self) -> Population<T, S> {
+        Population::evolve(self)
+    }
+
+    fn get_fittest(&self) -> Option<(Individual<T>, S)> {
+        Population::get_fittest(self)
+    }
+
+    fn get_statistics(&self) -> Statistics {
+        Population::get_statistics(self)
+    }
+
+    fn get_statistics_with_genomes(&self) -> Statistics
+        where T: Hash + Eq
+    {
+        Population::get_statistics_with_genomes(self)
+    }
+
+    fn notify(&self, event: &Event) -> bool {
+        Population::notify(self, event)
+    }
+
+    fn is_stop_requested(&self) -> bool {
+        self.stop_requested
+    }
+
+    fn get_direction(&self) -> Direction {
+        self.configuration.direction
     }
 }
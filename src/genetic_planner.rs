@@ -1,22 +1,49 @@
 extern crate rand;
-use rand::Rand;
-use rand::Rng;
+use rand::XorShiftRng;
 
-use std::cmp::PartialEq;
+use std::cmp::{PartialEq, Reverse};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
 
 use genetic::*;
+use generator::GeneContext;
+use pareto::Objectives;
+use island::{Archipelago, ArchipelagoConfiguration};
+use statistics::Statistics;
+use termination::{Termination, run_with_statistics};
+use checkpoint::{GeneCodec, load_from_file, save_to_file};
+use score::{Direction, Score};
+use error::{ConfigurationError, Error, check, check_rate};
+use replacement::Replacement;
+use restart::Restart;
+use builder::default_threadpool_size;
+use selection::Tournament;
+use crossover::UniformCrossover;
+use mutation::{RandomResetting, GeneInsertionMutation, GeneDeletionMutation, WeightedMutation};
 
 pub trait State
     where Self: Sized + Clone + Send + Sync + 'static
 {
+    /// Type of the heuristic, for example i32 for a number of steps
+    /// or OrdF64 for a distance
+    type Score: Score;
     /// Get the initial state
     fn get_initial_state() -> Self;
     /// Get a random action 
-    fn get_random_action() -> Action<Self>;
+    fn get_random_action() -> Action<Self> {
+        Self::get_random_action_with_rng(&mut rand::weak_rng())
+    }
+    /// Get a random action using the random number generator,
+    /// the same seed gives the same Plan
+    fn get_random_action_with_rng(rng: &mut XorShiftRng) -> Action<Self>;
     /// Verify if the current state is the goal
     fn is_goal(&self) -> bool;
     /// Get an aproximated distance to the goal state
-    fn get_heuristic(&self) -> i32;
+    fn get_heuristic(&self) -> Self::Score;
 }
 
 /// Contains the actions of a Plan 
@@ -28,6 +55,11 @@ pub struct Plan<T>
     pub state: T,
     /// Actions of the Plan
     pub actions: Vec<Action<T>>,
+    /// Statistics of each generation of the run which found the Plan, with
+    /// the statistics of the genomes, from the first generation
+    pub history: Vec<Statistics>,
+    /// Criterion which has ended the run which found the Plan
+    pub termination: Option<Termination>,
 }
 
 impl<T> Plan<T>
@@ -36,8 +68,10 @@ impl<T> Plan<T>
     /// Create a new Action
     pub fn new(state: T) -> Plan<T> {
         Plan {
-            state: state,
+            state,
             actions: Vec::new(),
+            history: Vec::new(),
+            termination: None,
         }
     }
 }
@@ -51,25 +85,86 @@ pub struct Action<T>
     pub name: String,
 }
 
-impl<T> Rand for Action<T>
-    where T: State + Clone
+/// Two actions are equal if they have the same name
+impl<T> PartialEq for Action<T>
+    where T: State + Clone + Send + Sync + 'static
 {
-    fn rand<R: Rng>(_: &mut R) -> Action<T> {
-        T::get_random_action()
+    fn eq(&self, other: &Action<T>) -> bool {
+        self.name == other.name
     }
 }
 
-impl<T> PartialEq for Action<T>
+impl<T> Eq for Action<T> where T: State + Clone + Send + Sync + 'static {}
+
+impl<T> Hash for Action<T>
     where T: State + Clone + Send + Sync + 'static
 {
-    fn eq(&self, other: &Action<T>) -> bool {
-        self.name != other.name
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+/// Contains the actions of a State by name, used to save the plans
+/// in a checkpoint and to restore them
+#[derive(Clone)]
+pub struct ActionRegistry<T>
+    where T: State + Clone + Send + Sync + 'static
+{
+    /// Registered actions
+    pub actions: HashMap<String, Action<T>>,
+}
+
+impl<T> ActionRegistry<T>
+    where T: State + Clone + Send + Sync + 'static
+{
+    /// Create a new ActionRegistry without actions
+    pub fn new() -> ActionRegistry<T> {
+        ActionRegistry { actions: HashMap::new() }
+    }
+
+    /// Return the ActionRegistry with an other action, an action
+    /// with the same name is replaced
+    pub fn register(mut self, action: Action<T>) -> ActionRegistry<T> {
+        self.actions.insert(action.name.clone(), action);
+        self
+    }
+
+    /// Get the action with the name
+    pub fn get(&self, name: &str) -> Option<Action<T>> {
+        self.actions.get(name).cloned()
+    }
+}
+
+impl<T> Default for ActionRegistry<T>
+    where T: State + Clone + Send + Sync + 'static
+{
+    fn default() -> ActionRegistry<T> {
+        ActionRegistry::new()
     }
 }
 
+impl<T> GeneCodec<Action<T>> for ActionRegistry<T>
+    where T: State + Clone + Send + Sync + 'static
+{
+    fn encode(&self, gene: &Action<T>) -> String {
+        gene.name.clone()
+    }
+
+    fn decode(&self, text: &str) -> Option<Action<T>> {
+        self.get(text)
+    }
+}
+
+/// Population of the plans of the State T
+pub type PlanPopulation<T> = Population<Action<T>, <T as State>::Score>;
+
 /// Contains the configuration of the Planner 
+#[derive(Debug,Clone)]
 pub struct PlannerConfiguration {
-    /// Max number of actions
+    /// Number of actions of the initial plans
+    pub initial_actions: usize,
+    /// Max number of actions of a plan, the plans can grow
+    /// from initial_actions up to max_actions
     pub max_actions: usize,
     /// Number of the Individual in the Population
     pub population_size: usize,
@@ -83,10 +178,193 @@ pub struct PlannerConfiguration {
     pub mutation_rate: f32,
     /// Number of thread used in the evolve function
     pub threadpool_size: usize,
+    /// Seed used to find the same Plan at each run, if None
+    /// the Plan can be different at each run
+    pub seed: Option<u64>,
+    /// Strategy used to leave the dead ends, where the plans stop improving,
+    /// with new random plans. If None the search can stay in a dead end
+    pub restart: Option<Restart>,
+}
+
+impl PlannerConfiguration {
+    /// Get a PlannerConfigurationBuilder with the defaults
+    pub fn builder() -> PlannerConfigurationBuilder {
+        PlannerConfigurationBuilder::new()
+    }
+
+    /// Verify the configuration, the error contains every problem found
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut problems = Vec::new();
+        if self.population_size == 0 {
+            problems.push(ConfigurationError::EmptyPopulation);
+        }
+        if self.tournmant_size == 0 {
+            problems.push(ConfigurationError::EmptyTournament);
+        }
+        if self.elitism_size > self.population_size {
+            problems.push(ConfigurationError::ElitismTooLarge {
+                elitism_size: self.elitism_size,
+                population_size: self.population_size,
+            });
+        }
+        if self.initial_actions == 0 || self.initial_actions > self.max_actions {
+            problems.push(ConfigurationError::InvalidActionNumber {
+                initial_actions: self.initial_actions,
+                max_actions: self.max_actions,
+            });
+        }
+        problems.extend(check_rate("uniform_rate", self.uniform_rate));
+        problems.extend(self.restart.and_then(|r| r.check()));
+        problems.extend(check_rate("mutation_rate", self.mutation_rate));
+        check(problems)
+    }
+}
+
+/// Builder of a PlannerConfiguration, the defaults are:
+///
+/// * initial_actions: 10
+/// * max_actions: twice initial_actions
+/// * population_size: 100
+/// * tournmant_size: 4
+/// * elitism_size: 1
+/// * uniform_rate: 0.5
+/// * mutation_rate: 0.3
+/// * threadpool_size: the number of available CPUs
+/// * seed: None
+///
+/// The presets change some of the defaults, the setters called
+/// after a preset override its values
+#[derive(Debug,Clone)]
+pub struct PlannerConfigurationBuilder {
+    configuration: PlannerConfiguration,
+    max_actions: Option<usize>,
+}
+
+impl PlannerConfigurationBuilder {
+    /// Create a new PlannerConfigurationBuilder with the defaults
+    pub fn new() -> PlannerConfigurationBuilder {
+        PlannerConfigurationBuilder {
+            configuration: PlannerConfiguration {
+                initial_actions: 10,
+                max_actions: 20,
+                population_size: 100,
+                tournmant_size: 4,
+                elitism_size: 1,
+                uniform_rate: 0.5,
+                mutation_rate: 0.3,
+                threadpool_size: default_threadpool_size(),
+                seed: None,
+                restart: None,
+            },
+            max_actions: None,
+        }
+    }
+
+    /// Preset for short plans: 32 plans, tournaments of 2
+    /// and evaluated on a single thread
+    pub fn small_search(self) -> PlannerConfigurationBuilder {
+        self.population_size(32)
+            .tournmant_size(2)
+            .elitism_size(1)
+            .threadpool_size(1)
+    }
+
+    /// Preset which explores many different plans: 200 plans,
+    /// tournaments of 2 and mutation_rate 0.7
+    pub fn exploratory(self) -> PlannerConfigurationBuilder {
+        self.population_size(200)
+            .tournmant_size(2)
+            .mutation_rate(0.7)
+            .elitism_size(1)
+    }
+
+    /// Preset which refines the best plans: 50 plans, tournaments
+    /// of 10, mutation_rate 0.1 and 5 elites
+    pub fn exploitative(self) -> PlannerConfigurationBuilder {
+        self.population_size(50)
+            .tournmant_size(10)
+            .mutation_rate(0.1)
+            .elitism_size(5)
+    }
+
+    /// Set the number of actions of the initial plans
+    pub fn initial_actions(mut self, initial_actions: usize) -> PlannerConfigurationBuilder {
+        self.configuration.initial_actions = initial_actions;
+        self
+    }
+
+    /// Set the max number of actions of a plan
+    pub fn max_actions(mut self, max_actions: usize) -> PlannerConfigurationBuilder {
+        self.max_actions = Some(max_actions);
+        self
+    }
+
+    /// Set the number of plans in the Population
+    pub fn population_size(mut self, population_size: usize) -> PlannerConfigurationBuilder {
+        self.configuration.population_size = population_size;
+        self
+    }
+
+    /// Set the size of the tournaments used to select the parents
+    pub fn tournmant_size(mut self, tournmant_size: usize) -> PlannerConfigurationBuilder {
+        self.configuration.tournmant_size = tournmant_size;
+        self
+    }
+
+    /// Set the number of plans copied in the next generation
+    pub fn elitism_size(mut self, elitism_size: usize) -> PlannerConfigurationBuilder {
+        self.configuration.elitism_size = elitism_size;
+        self
+    }
+
+    /// Set the parameter used by the crossover
+    pub fn uniform_rate(mut self, uniform_rate: f32) -> PlannerConfigurationBuilder {
+        self.configuration.uniform_rate = uniform_rate;
+        self
+    }
+
+    /// Set the parameter used by the mutation
+    pub fn mutation_rate(mut self, mutation_rate: f32) -> PlannerConfigurationBuilder {
+        self.configuration.mutation_rate = mutation_rate;
+        self
+    }
+
+    /// Set the number of threads used to create the next generations
+    pub fn threadpool_size(mut self, threadpool_size: usize) -> PlannerConfigurationBuilder {
+        self.configuration.threadpool_size = threadpool_size;
+        self
+    }
+
+    /// Set the seed used to find the same Plan at each run
+    pub fn seed(mut self, seed: u64) -> PlannerConfigurationBuilder {
+        self.configuration.seed = Some(seed);
+        self
+    }
+
+    /// Set the strategy used to leave the dead ends with new random plans
+    pub fn restart(mut self, restart: Restart) -> PlannerConfigurationBuilder {
+        self.configuration.restart = Some(restart);
+        self
+    }
+
+    /// Get the PlannerConfiguration, return an error
+    /// which contains every problem if it is not valid
+    pub fn build(self) -> Result<PlannerConfiguration, Error> {
+        let mut c = self.configuration;
+        c.max_actions = self.max_actions.unwrap_or(2 * c.initial_actions);
+        c.validate()?;
+        Ok(c)
+    }
+}
+
+impl Default for PlannerConfigurationBuilder {
+    fn default() -> PlannerConfigurationBuilder {
+        PlannerConfigurationBuilder::new()
+    }
 }
 
 /// Apply the Action of the Individual to the initial state
-fn apply_actions<T>(i: Individual<Action<T>>) -> Plan<T>
+fn apply_actions<T>(i: &Individual<Action<T>>) -> Plan<T>
     where T: State + Clone + Send + Sync + 'static
 {
     let mut state = Some(T::get_initial_state());
@@ -94,11 +372,11 @@ fn apply_actions<T>(i: Individual<Action<T>>) -> Plan<T>
     let mut used_actions: Vec<Action<T>> = Vec::new();
     let mut actions = i.genes.iter();
     let mut action = actions.next();
-    while action.is_some() && state.clone().is_some() && !state.clone().unwrap().is_goal() {
-        state = (action.clone().unwrap().action)(state.unwrap());
-        if state.clone().is_some() {
+    while action.is_some() && state.is_some() && !state.clone().unwrap().is_goal() {
+        state = (action.unwrap().action)(state.unwrap());
+        if state.is_some() {
             old_state = state.clone();
-            let tmp = action.clone().unwrap();
+            let tmp = action.unwrap();
             let last_action = Action {
                 action: tmp.action,
                 name: tmp.name.to_string(),
@@ -112,127 +390,271 @@ fn apply_actions<T>(i: Individual<Action<T>>) -> Plan<T>
             Plan {
                 state: old_state.unwrap(),
                 actions: used_actions,
+                history: Vec::new(),
+                termination: None,
             }
         }
         Some(sstate) => {
             Plan {
                 state: sstate,
                 actions: used_actions,
+                history: Vec::new(),
+                termination: None,
             }
         }
     }
 }
 
-/// Calculate the fitness of an Individual<Action<T>>
-fn fitness_planner<T>(i: Individual<Action<T>>) -> i32
+/// Calculate the fitness of an Individual<Action<T>>, the heuristic
+/// of the reached state which is minimised
+fn fitness_planner<T>(i: &Individual<Action<T>>) -> T::Score
     where T: State + Clone + Send + Sync + 'static
 {
     let node = apply_actions(i);
-    -node.state.get_heuristic()
+    node.state.get_heuristic()
 }
 
-/// Convert PlannerConfiguration to PopulationConfiguration
-fn get_population_configuration<T>(c: PlannerConfiguration) -> PopulationConfiguration<Action<T>>
+/// Convert PlannerConfiguration to PopulationConfiguration, the result can be
+/// customized and used with the find_*_from_population functions.
+/// Return an error if the PlannerConfiguration is not valid
+pub fn get_population_configuration<T>
+    (c: PlannerConfiguration)
+     -> Result<PopulationConfiguration<Action<T>, T::Score>, Error>
     where T: State + Clone + Send + Sync + 'static
 {
-    PopulationConfiguration {
-        genenumber: c.max_actions,
+    c.validate()?;
+    Ok(PopulationConfiguration {
+        genenumber: c.initial_actions,
+        min_genenumber: 1,
+        max_genenumber: c.max_actions,
         population_size: c.population_size,
         elitism_size: c.elitism_size,
-        tournmant_size: c.tournmant_size,
+        replacement: Replacement::Generational,
+        niching: None,
+        eliminate_duplicates: false,
+        restart: c.restart,
+        mutation_adaptation: None,
+        crossover_adaptation: None,
+        observers: Vec::new(),
+        selection: Arc::new(Tournament::new(c.tournmant_size)),
+        crossover: Arc::new(UniformCrossover),
         uniform_rate: c.uniform_rate,
+        mutation: Arc::new(WeightedMutation::new()
+            .add(2f64, RandomResetting)
+            .add(1f64, GeneInsertionMutation)
+            .add(1f64, GeneDeletionMutation)),
         mutation_rate: c.mutation_rate,
-        fitness: fitness_planner,
+        memetic: None,
+        fitness: Arc::new(|i: &Individual<Action<T>>| fitness_planner(i)),
+        batch_fitness: None,
+        generator: Arc::new(|_: &GeneContext, rng: &mut XorShiftRng| {
+            T::get_random_action_with_rng(rng)
+        }),
+        direction: Direction::Minimise,
         threadpool_size: c.threadpool_size,
-    }
+        cache: None,
+        objectives: None,
+        seed: c.seed,
+    })
+}
+
+/// Create an Archipelago of islands Populations from the PlannerConfiguration,
+/// with a seed each island evolves differently from the others
+pub fn get_archipelago<T>(c: PlannerConfiguration,
+                          islands: usize,
+                          configuration: ArchipelagoConfiguration)
+                          -> Result<Archipelago<Action<T>, T::Score>, Error>
+    where T: State + Clone + Send + Sync + 'static
+{
+    let pc = get_population_configuration(c)?;
+    let populations = (0..islands)
+        .map(|i| {
+            let mut ipc = pc.clone();
+            ipc.seed = pc.seed.map(|seed| seed.wrapping_add(i as u64));
+            Population::new(ipc)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Archipelago::new(populations, configuration)
+}
+
+/// Save the Population of a planner in the file at path, the actions are saved by name
+pub fn save_population<T, P>(pop: &PlanPopulation<T>, path: P) -> Result<(), Error>
+    where T: State + Clone + Send + Sync + 'static,
+          T::Score: Display,
+          P: AsRef<Path>
+{
+    save_to_file(pop, path, &ActionRegistry::new())
+}
+
+/// Load the Population of a planner saved in the file at path, the actions are restored
+/// from the registry. The Population can be used by the find_*_from_population functions
+pub fn load_population<T, P>(path: P,
+                             c: PlannerConfiguration,
+                             registry: &ActionRegistry<T>)
+                             -> Result<PlanPopulation<T>, Error>
+    where T: State + Clone + Send + Sync + 'static,
+          T::Score: FromStr,
+          P: AsRef<Path>
+{
+    load_from_file(path, get_population_configuration(c)?, registry)
+}
+
+/// Get the objectives of the multi objective planner: the opposite of
+/// the heuristic of the reached state and the opposite of the number of actions
+pub fn get_planner_objectives<T>() -> Objectives<Action<T>>
+    where T: State + Clone + Send + Sync + 'static
+{
+    Arc::new(|i: &Individual<Action<T>>| {
+        let plan = apply_actions(i);
+        vec![-plan.state.get_heuristic().to_f64(), -(plan.actions.len() as f64)]
+    })
 }
 
-/// Find a Plan and its Population starting a Population
-pub fn find_solution_and_population_from_population<T>(pop: Population<Action<T>>)
-                                                       -> (Plan<T>, Population<Action<T>>)
+/// Find the Pareto front of the plans and its Population after <iterations> iterations
+/// starting from a Population, each plan of the front trades the distance to the goal
+/// against the number of actions. If the configuration of the Population has no objectives
+/// the objectives of get_planner_objectives are used
+pub fn find_pareto_front_and_population_after_iterations_from_population<T>
+    (pop: PlanPopulation<T>,
+     iterations: usize)
+     -> Result<(Vec<Plan<T>>, PlanPopulation<T>), Error>
     where T: State + Clone + Send + Sync + 'static
 {
     let mut pop = pop.clone();
-    let mut best_actions = pop.get_fittest();
-    let mut node: Plan<T> = apply_actions(best_actions.unwrap().0);
-    while !node.state.is_goal() {
+    if pop.configuration.objectives.is_none() {
+        pop.configuration.objectives = Some(get_planner_objectives());
+    }
+    for _ in 0..iterations {
         pop = pop.evolve();
-        best_actions = pop.get_fittest();
-        node = apply_actions(best_actions.unwrap().0);
     }
-    (Plan {
-        state: node.state,
-        actions: node.actions,
-    },
-     pop)
+    let mut front: Vec<(Plan<T>, Vec<f64>)> = Vec::new();
+    for (i, objectives) in pop.get_pareto_front() {
+        if front.iter().all(|a| a.1 != objectives) {
+            front.push((apply_actions(&i), objectives));
+        }
+    }
+    front.sort_by_key(|a| Reverse(a.0.actions.len()));
+    Ok((front.into_iter().map(|a| a.0).collect(), pop))
+}
+
+/// Find the Pareto front of the plans after <iterations> iterations,
+/// from the longest to the shortest plan
+pub fn find_pareto_front_after_iterations<T>(c: PlannerConfiguration,
+                                             iterations: usize)
+                                             -> Result<Vec<Plan<T>>, Error>
+    where T: State + Clone + Send + Sync + 'static
+{
+    let mut pc = get_population_configuration(c)?;
+    pc.objectives = Some(get_planner_objectives());
+    let pop = Population::new(pc)?;
+    Ok(find_pareto_front_and_population_after_iterations_from_population(pop, iterations)?.0)
+}
+
+/// Find the best Plan and its Population starting from a Population, or an Archipelago,
+/// evolving it until the termination is satisfied or an Observer asks to stop.
+/// The Plan contains the criterion which has ended the run.
+/// Return an error if the Population has no Individuals or the termination is not valid
+pub fn find_plan_and_population_from_population<T, P>(pop: P,
+                                                      termination: &Termination)
+                                                      -> Result<(Plan<T>, P), Error>
+    where T: State + Clone + Send + Sync + 'static,
+          P: Evolution<Action<T>, T::Score>
+{
+    if pop.get_fittest().is_none() {
+        return Err(Error::EmptyPopulation);
+    }
+    let goal = |p: &P| p.get_fittest().is_some_and(|f| apply_actions(&f.0).state.is_goal());
+    let result = run_with_statistics(pop, termination, goal, |p: &P| {
+        p.get_statistics_with_genomes()
+    })?;
+    let fittest = result.population.get_fittest().ok_or(Error::EmptyPopulation)?;
+    let mut node = apply_actions(&fittest.0);
+    node.history = result.history;
+    node.termination = Some(result.termination);
+    Ok((node, result.population))
+}
+
+/// Find the best Plan, evolving a new Population until the termination is satisfied
+pub fn find_plan<T>(c: PlannerConfiguration, termination: &Termination) -> Result<Plan<T>, Error>
+    where T: State + Clone + Send + Sync + 'static
+{
+    let pop = Population::new(get_population_configuration(c)?)?;
+    Ok(find_plan_and_population_from_population(pop, termination)?.0)
+}
+
+/// Find a Plan and its Population starting a Population, or an Archipelago.
+/// If an Observer asks to stop, the best Plan found so far is returned
+pub fn find_solution_and_population_from_population<T, P>(pop: P) -> Result<(Plan<T>, P), Error>
+    where T: State + Clone + Send + Sync + 'static,
+          P: Evolution<Action<T>, T::Score>
+{
+    find_plan_and_population_from_population(pop, &Termination::GoalReached)
 }
 
 /// Find a Plan and its Population
-pub fn find_solution_and_population<T>(c: PlannerConfiguration) -> (Plan<T>, Population<Action<T>>)
+pub fn find_solution_and_population<T>(c: PlannerConfiguration)
+                                       -> Result<(Plan<T>, PlanPopulation<T>), Error>
     where T: State + Clone + Send + Sync + 'static
 {
-    let pc = get_population_configuration(c);
-    let pop = Population::new(pc);
+    let pc = get_population_configuration(c)?;
+    let pop = Population::new(pc)?;
     find_solution_and_population_from_population(pop)
 }
 
 /// Find a plan 
-pub fn find_solution<T>(c: PlannerConfiguration) -> Plan<T>
+pub fn find_solution<T>(c: PlannerConfiguration) -> Result<Plan<T>, Error>
     where T: State + Clone + Send + Sync + 'static
 {
-    find_solution_and_population(c).0
+    Ok(find_solution_and_population(c)?.0)
 }
 
-/// Find a plan starting from a Population
-pub fn find_solution_from_population<T>(pop: Population<Action<T>>) -> Plan<T>
-    where T: State + Clone + Send + Sync + 'static
+/// Find a plan starting from a Population, or an Archipelago
+pub fn find_solution_from_population<T, P>(pop: P) -> Result<Plan<T>, Error>
+    where T: State + Clone + Send + Sync + 'static,
+          P: Evolution<Action<T>, T::Score>
 {
-    find_solution_and_population_from_population(pop).0
+    Ok(find_solution_and_population_from_population(pop)?.0)
 }
 
-/// Found the best plan and its Population, after <iterations> iterations
-pub fn find_best_and_population_after_iterations_from_population<T>
-    (pop: Population<Action<T>>,
-     iterations: usize)
-     -> (Plan<T>, Population<Action<T>>)
-    where T: State + Clone + Send + Sync + 'static
+/// Found the best plan and its Population, or Archipelago, after <iterations> iterations
+/// or when an Observer asks to stop
+pub fn find_best_and_population_after_iterations_from_population<T, P>(pop: P,
+                                                                     iterations: usize)
+                                                                     -> Result<(Plan<T>, P), Error>
+    where T: State + Clone + Send + Sync + 'static,
+          P: Evolution<Action<T>, T::Score>
 {
-    let mut pop = pop.clone();
-    for _ in 0..iterations {
-        pop = pop.evolve();
-    }
-    let best_actions = pop.get_fittest();
-    let node = apply_actions(best_actions.unwrap().0);
-    (Plan {
-        state: node.state,
-        actions: node.actions,
-    },
-     pop)
+    find_plan_and_population_from_population(pop, &Termination::MaxGenerations(iterations))
 }
 
 /// Found the best plan and its Population after <iterations> iterations
-pub fn find_best_and_population_after_iterations<T>(c: PlannerConfiguration,
-                                                    iterations: usize)
-                                                    -> (Plan<T>, Population<Action<T>>)
+pub fn find_best_and_population_after_iterations<T>
+    (c: PlannerConfiguration,
+     iterations: usize)
+     -> Result<(Plan<T>, PlanPopulation<T>), Error>
     where T: State + Clone + Send + Sync + 'static
 {
-    let pc = get_population_configuration(c);
-    let pop = Population::new(pc);
+    let pc = get_population_configuration(c)?;
+    let pop = Population::new(pc)?;
     find_best_and_population_after_iterations_from_population(pop, iterations)
 }
 
 /// Found the best plan after <iterations> iterations
-pub fn find_best_after_iterations<T>(c: PlannerConfiguration, iterations: usize) -> Plan<T>
+pub fn find_best_after_iterations<T>(c: PlannerConfiguration,
+                                     iterations: usize)
+                                     -> Result<Plan<T>, Error>
     where T: State + Clone + Send + Sync + 'static
 {
-    find_best_and_population_after_iterations(c, iterations).0
+    Ok(find_best_and_population_after_iterations(c, iterations)?.0)
 }
 
-/// Found the best plan after <iterations> iterations starting from a Population
-pub fn find_best_after_iterations_from_population<T>(pop: Population<Action<T>>,
-                                                     iterations: usize)
-                                                     -> Plan<T>
-    where T: State + Clone + Send + Sync + 'static
+/// Found the best plan after <iterations> iterations starting from a Population,
+/// or an Archipelago
+pub fn find_best_after_iterations_from_population<T, P>(pop: P,
+                                                      iterations: usize)
+                                                      -> Result<Plan<T>, Error>
+    where T: State + Clone + Send + Sync + 'static,
+          P: Evolution<Action<T>, T::Score>
 {
-    find_best_and_population_after_iterations_from_population(pop, iterations).0
+    Ok(find_best_and_population_after_iterations_from_population(pop, iterations)?.0)
 }
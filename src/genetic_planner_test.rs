@@ -1,7 +1,16 @@
+#![cfg(test)]
+
 extern crate rand;
 
-use rand::Rng;
+use rand::{Rng, XorShiftRng};
 use genetic_planner::*;
+use island::{ArchipelagoConfiguration, Topology};
+use observer::{Control, Event};
+use genetic::Population;
+use score::OrdF64;
+use error::Error;
+use termination::Termination;
+use std::sync::Arc;
 
 #[derive(Clone,PartialEq)]
 struct Coin {
@@ -9,13 +18,15 @@ struct Coin {
 }
 
 impl State for Coin {
+    type Score = i32;
+
     fn get_initial_state() -> Coin {
         Coin { head: false }
     }
 
-    fn get_random_action() -> Action<Coin> {
-        let a = rand::thread_rng().gen::<u8>();
-        let half = u8::max_value() / 2;
+    fn get_random_action_with_rng(rng: &mut XorShiftRng) -> Action<Coin> {
+        let a = rng.gen::<u8>();
+        let half = u8::MAX / 2;
         if a > half {
             Action::<Coin> {
                 action: flip,
@@ -51,34 +62,235 @@ fn flop(_: Coin) -> Option<Coin> {
     None
 }
 
+#[derive(Clone,PartialEq)]
+struct Walker {
+    pub position: f64,
+}
+
+impl State for Walker {
+    type Score = OrdF64;
+
+    fn get_initial_state() -> Walker {
+        Walker { position: 0f64 }
+    }
+
+    fn get_random_action_with_rng(rng: &mut XorShiftRng) -> Action<Walker> {
+        if rng.gen::<bool>() {
+            Action::<Walker> {
+                action: forward,
+                name: "Forward".to_string(),
+            }
+        } else {
+            Action::<Walker> {
+                action: backward,
+                name: "Backward".to_string(),
+            }
+        }
+    }
+
+    fn is_goal(&self) -> bool {
+        self.position >= 1f64
+    }
+
+    fn get_heuristic(&self) -> OrdF64 {
+        OrdF64(1f64 - self.position)
+    }
+}
+
+fn forward(w: Walker) -> Option<Walker> {
+    Some(Walker { position: w.position + 0.25 })
+}
+
+fn backward(w: Walker) -> Option<Walker> {
+    if w.position > 0f64 {
+        Some(Walker { position: w.position - 0.25 })
+    } else {
+        None
+    }
+}
+
+#[allow(dead_code)]
+fn builder() -> PlannerConfigurationBuilder {
+    PlannerConfiguration::builder()
+        .initial_actions(4)
+        .max_actions(4)
+        .population_size(16)
+        .mutation_rate(0.5)
+        .threadpool_size(2)
+}
+
 #[test]
 fn test_find_solution() {
-    let pc = PlannerConfiguration {
-        max_actions: 4,
-        population_size: 16,
-        tournmant_size: 4,
-        elitism_size: 1,
-        uniform_rate: 0.5,
-        mutation_rate: 0.5,
-        threadpool_size: 2,
-    };
-    let n: Plan<Coin> = find_solution(pc);
+    let pc = builder().build().unwrap();
+    let n: Plan<Coin> = find_solution(pc).unwrap();
     assert!(n.state.is_goal());
     assert!(n.actions.len() == 1);
-    assert!(n.actions.get(0).unwrap().name == "Flip");
+    assert!(n.actions.first().unwrap().name == "Flip");
+}
+
+#[test]
+fn test_float_heuristic() {
+    let pc = builder().initial_actions(6).max_actions(8).seed(2).build().unwrap();
+    let (n, pop) = find_best_and_population_after_iterations::<Walker>(pc, 20).unwrap();
+    assert_eq!(pop.get_fittest().unwrap().1, n.state.get_heuristic());
+    assert!(n.history.iter().all(|s| s.best * 4f64 == (s.best * 4f64).round()));
+    assert!(n.history.windows(2).all(|w| w[1].best <= w[0].best));
 }
 
 #[test]
 #[allow(unused_variables)]
 fn test_find_best_fit() {
-    let pc = PlannerConfiguration {
-        max_actions: 4,
-        population_size: 16,
-        tournmant_size: 4,
-        elitism_size: 1,
-        uniform_rate: 0.5,
-        mutation_rate: 0.5,
-        threadpool_size: 2,
+    let pc = builder().build().unwrap();
+    let n: Plan<Coin> = find_best_after_iterations(pc, 10).unwrap();
+}
+
+#[test]
+fn test_seed() {
+    let pc = |threadpool_size| {
+        builder()
+            .initial_actions(8)
+            .max_actions(8)
+            .threadpool_size(threadpool_size)
+            .seed(42)
+            .build()
+            .unwrap()
+    };
+    let (_, p1) = find_best_and_population_after_iterations::<Coin>(pc(1), 5).unwrap();
+    let (_, p2) = find_best_and_population_after_iterations::<Coin>(pc(4), 5).unwrap();
+    let names = |p: &::genetic::Population<Action<Coin>>| {
+        p.individuals_and_scores
+            .iter()
+            .map(|a| a.0.genes.iter().map(|g| g.name.clone()).collect::<Vec<String>>())
+            .collect::<Vec<Vec<String>>>()
     };
-    let n: Plan<Coin> = find_best_after_iterations(pc, 10);
+    assert_eq!(names(&p1), names(&p2));
+}
+
+#[test]
+fn test_action_eq() {
+    let flip1 = Action::<Coin> {
+        action: flip,
+        name: "Flip".to_string(),
+    };
+    let flip2 = flip1.clone();
+    let flop1 = Action::<Coin> {
+        action: flop,
+        name: "Flop".to_string(),
+    };
+    assert!(flip1 == flip2);
+    assert!(flip1 != flop1);
+}
+
+#[test]
+fn test_pareto_front() {
+    let pc = builder().seed(1).build().unwrap();
+    let front: Vec<Plan<Coin>> = find_pareto_front_after_iterations(pc, 10).unwrap();
+    assert!(!front.is_empty());
+    for w in front.windows(2) {
+        assert!(w[0].actions.len() > w[1].actions.len());
+        assert!(w[0].state.get_heuristic() < w[1].state.get_heuristic());
+    }
+}
+
+#[test]
+fn test_archipelago() {
+    let pc = builder()
+        .population_size(8)
+        .tournmant_size(2)
+        .threadpool_size(1)
+        .seed(3)
+        .build()
+        .unwrap();
+    let ac = ArchipelagoConfiguration {
+        migration_interval: 1,
+        migration_size: 1,
+        topology: Topology::Ring,
+        seed: Some(3),
+    };
+    let archipelago = get_archipelago::<Coin>(pc, 3, ac).unwrap();
+    let (n, archipelago) = find_solution_and_population_from_population(archipelago).unwrap();
+    assert!(n.state.is_goal());
+    assert_eq!(archipelago.islands.len(), 3);
+}
+
+#[test]
+fn test_history() {
+    let pc = builder().seed(5).build().unwrap();
+    let n: Plan<Coin> = find_best_after_iterations(pc, 3).unwrap();
+    assert_eq!(n.history.len(), 4);
+    for (generation, s) in n.history.iter().enumerate() {
+        assert_eq!(s.generation, generation);
+        assert!(s.best <= s.worst);
+        assert!(s.diversity.is_some_and(|d| (0f64..=1f64).contains(&d)));
+        assert!(s.unique_genomes.is_some_and(|u| (1..=16).contains(&u)));
+    }
+}
+
+#[test]
+fn test_observer_stop() {
+    let pc = builder().seed(5).build().unwrap();
+    let mut c = get_population_configuration::<Coin>(pc).unwrap();
+    c.observers.push(Arc::new(|event: &Event| match *event {
+        Event::GenerationFinished(s) if s.generation == 3 => Control::Stop,
+        _ => Control::Continue,
+    }));
+    let pop = Population::new(c).unwrap();
+    let (n, pop) = find_best_and_population_after_iterations_from_population(pop, 100).unwrap();
+    assert_eq!(pop.generation, 3);
+    assert_eq!(n.history.len(), 4);
+}
+
+#[test]
+fn test_termination() {
+    let pc = builder().seed(7).build().unwrap();
+    let termination = Termination::GoalReached.or(Termination::MaxGenerations(50));
+    let n: Plan<Coin> = find_plan(pc, &termination).unwrap();
+    match n.termination {
+        Some(Termination::GoalReached) => assert!(n.state.is_goal()),
+        Some(Termination::MaxGenerations(50)) => assert_eq!(n.history.len(), 51),
+        _ => panic!("unexpected termination"),
+    }
+}
+
+#[test]
+fn test_checkpoint() {
+    let registry = ActionRegistry::new()
+        .register(Action {
+            action: flip,
+            name: "Flip".to_string(),
+        })
+        .register(Action {
+            action: flop,
+            name: "Flop".to_string(),
+        });
+    let path = ::std::env::temp_dir().join("genetic_planner_planner_checkpoint_test.txt");
+    let pc = builder().seed(9).build().unwrap();
+    let (_, pop) = find_best_and_population_after_iterations::<Coin>(pc, 2).unwrap();
+    save_population(&pop, &path).unwrap();
+    let loaded = load_population(&path, builder().build().unwrap(), &registry).unwrap();
+    ::std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.generation, 2);
+    assert_eq!(loaded.configuration.seed, Some(9));
+    assert_eq!(loaded.individuals_and_scores.len(), 16);
+    for (a, b) in loaded.individuals_and_scores.iter().zip(pop.individuals_and_scores.iter()) {
+        assert!(a.0 == b.0 && a.1 == b.1);
+    }
+    let n = find_solution_from_population(loaded).unwrap();
+    assert!(n.state.is_goal());
+}
+
+#[test]
+fn test_errors() {
+    let pc = builder().population_size(0).tournmant_size(0).mutation_rate(2.0).build();
+    match pc {
+        Err(Error::InvalidConfiguration(problems)) => assert_eq!(problems.len(), 4),
+        _ => panic!("the configuration is not valid"),
+    }
+    let pc = builder().population_size(4).tournmant_size(2).build().unwrap();
+    let c = get_population_configuration::<Coin>(pc).unwrap();
+    let pop = Population::new_with_vec(Vec::new(), c, 0).unwrap();
+    match find_best_after_iterations_from_population::<Coin, _>(pop, 3) {
+        Err(Error::EmptyPopulation) => {}
+        _ => panic!("the population is empty"),
+    }
 }
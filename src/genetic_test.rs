@@ -1,7 +1,14 @@
-#[cfg(test)]
+#![cfg(test)]
 
 extern crate rand;
 use genetic::*;
+use generator::RandomGenes;
+use score::{Direction, OrdF64};
+use selection::Tournament;
+use mutation::GeneInsertionMutation;
+use test_helpers::configuration_builder;
+
+use std::sync::Arc;
 
 #[test]
 #[allow(dead_code)]
@@ -37,10 +44,10 @@ fn crossover_test() {
 }
 
 #[allow(dead_code)]
-fn simple_fitness(i: Individual<u8>) -> i32 {
+fn simple_fitness(i: &Individual<u8>) -> i32 {
     let mut acc = 0i32;
-    for g in i.genes {
-        if g > 127 {
+    for g in &i.genes {
+        if *g > 127 {
             acc += 1
         }
     }
@@ -51,41 +58,40 @@ fn simple_fitness(i: Individual<u8>) -> i32 {
 fn fitness() {
     let vec: Vec<u8> = vec![0, 1, 2, 3, 4, 5, 128, 129];
     let i = Individual::new_with_vec(vec);
-    let f = simple_fitness(i);
+    let f = simple_fitness(&i);
     assert_eq!(f, 2);
     let vec2: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 0];
     let i2 = Individual::new_with_vec(vec2);
-    let f2 = simple_fitness(i2);
+    let f2 = simple_fitness(&i2);
     assert_eq!(f2, 0);
 }
 
 #[allow(dead_code)]
 fn default_population_configuration() -> PopulationConfiguration<u8> {
-    PopulationConfiguration {
-        population_size: 64,
-        fitness: simple_fitness,
-        genenumber: 8,
-        mutation_rate: 0.5f32,
-        uniform_rate: 0.5f32,
-        tournmant_size: 16,
-        elitism_size: 2,
-        threadpool_size: 8,
-    }
+    PopulationConfiguration::builder(simple_fitness)
+        .population_size(64)
+        .genenumber(8)
+        .mutation_rate(0.5f32)
+        .selection(Tournament::new(16))
+        .elitism_size(2)
+        .threadpool_size(8)
+        .build()
+        .unwrap()
 }
 
 #[test]
 #[allow(unused_variables)]
 fn create_population() {
-    let p = Population::<u8>::new(default_population_configuration());
+    let p = Population::<u8>::new(default_population_configuration()).unwrap();
 }
 
 #[test]
 fn get_fittest() {
-    let p = Population::<u8>::new(default_population_configuration());
+    let p = Population::<u8>::new(default_population_configuration()).unwrap();
     let fittest = p.get_fittest().unwrap().clone();
     let mut fittest2 = p.individuals_and_scores.first().unwrap().clone();
     for ind in p.individuals_and_scores {
-        let f = (p.configuration.fitness)(ind.clone().0);
+        let f = (p.configuration.fitness)(&ind.0);
         if f > fittest2.1 {
             fittest2 = (ind.0, f);
         }
@@ -93,9 +99,36 @@ fn get_fittest() {
     assert_eq!(fittest.1, fittest2.1);
 }
 
+#[test]
+fn get_top_small_population() {
+    let p = Population::<u8>::new(default_population_configuration()).unwrap();
+    assert_eq!(p.get_top(64), p.individuals_and_scores);
+    assert_eq!(p.get_top(100), p.individuals_and_scores);
+    let mut c = default_population_configuration();
+    c.elitism_size = 64;
+    let pe = Population::<u8>::new(c).unwrap().evolve();
+    assert_eq!(pe.individuals_and_scores.len(), 64);
+}
+
+#[test]
+fn get_top_distinct() {
+    let scored = |genes: Vec<u8>, score| (Individual::new_with_vec(genes), score);
+    let v = vec![scored(vec![1], 5), scored(vec![2], 10), scored(vec![3], 3)];
+    let p = Population::new_with_vec(v, default_population_configuration(), 0).unwrap();
+    assert_eq!(p.get_top(2), vec![scored(vec![2], 10), scored(vec![1], 5)]);
+    let v = vec![scored(vec![1], 5), scored(vec![1], 5), scored(vec![3], 3)];
+    let p = Population::new_with_vec(v, default_population_configuration(), 0).unwrap();
+    assert_eq!(p.get_top(2), vec![scored(vec![1], 5), scored(vec![3], 3)]);
+    let mut c = default_population_configuration();
+    c.direction = Direction::Minimise;
+    let v = vec![scored(vec![1], 5), scored(vec![2], 10), scored(vec![3], 3)];
+    let p = Population::new_with_vec(v, c, 0).unwrap();
+    assert_eq!(p.get_top(2), vec![scored(vec![3], 3), scored(vec![1], 5)]);
+}
+
 #[test]
 fn evolve() {
-    let p = Population::<u8>::new(default_population_configuration());
+    let p = Population::<u8>::new(default_population_configuration()).unwrap();
     let pe = p.evolve();
     assert_eq!(p.individuals_and_scores.len(),
                pe.individuals_and_scores.len());
@@ -104,8 +137,182 @@ fn evolve() {
 
 #[test]
 fn complete_evolve() {
-    let mut p = Population::<u8>::new(default_population_configuration());
+    let mut p = Population::<u8>::new(default_population_configuration()).unwrap();
     while p.get_fittest().unwrap().1 < 4 {
         p = p.evolve();
     }
 }
+
+#[test]
+fn closure_fitness() {
+    let target: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let mut c = default_population_configuration();
+    c.fitness = Arc::new(move |i: &Individual<u8>| {
+        i.genes.iter().zip(target.iter()).filter(|&(a, b)| a == b).count() as i32
+    });
+    let p = Population::<u8>::new(c).unwrap();
+    for &(ref i, score) in &p.individuals_and_scores {
+        assert_eq!((p.configuration.fitness)(i), score);
+    }
+    assert_eq!(p.individuals_and_scores.len(), 64);
+}
+
+#[test]
+fn variable_length_evolve() {
+    let mut c = default_population_configuration();
+    c.genenumber = 2;
+    c.min_genenumber = 1;
+    c.max_genenumber = 12;
+    c.mutation = Arc::new(GeneInsertionMutation);
+    c.mutation_rate = 1f32;
+    let mut p = Population::<u8>::new(c).unwrap();
+    for _ in 0..5 {
+        p = p.evolve();
+    }
+    assert!(p.individuals_and_scores.iter().all(|a| a.0.genes.len() <= 12));
+    assert!(p.individuals_and_scores.iter().any(|a| a.0.genes.len() > 2));
+}
+
+#[test]
+fn bound_length() {
+    let mut rng = rand::weak_rng();
+    let i = Individual::new_with_vec(vec![1u8, 2, 3, 4]);
+    assert_eq!(i.bound_length(0, 2, &RandomGenes, &mut rng).genes, vec![1u8, 2]);
+    assert_eq!(i.bound_length(6, 8, &RandomGenes, &mut rng).genes.len(), 6);
+    assert_eq!(i.bound_length(2, 8, &RandomGenes, &mut rng), i);
+}
+
+#[test]
+fn seed() {
+    let configuration = |threadpool_size| {
+        let mut c = default_population_configuration();
+        c.seed = Some(7);
+        c.threadpool_size = threadpool_size;
+        c
+    };
+    let mut p1 = Population::<u8>::new(configuration(1)).unwrap();
+    let mut p2 = Population::<u8>::new(configuration(8)).unwrap();
+    for _ in 0..3 {
+        p1 = p1.evolve();
+        p2 = p2.evolve();
+    }
+    assert_eq!(p1.individuals_and_scores, p2.individuals_and_scores);
+}
+
+#[test]
+fn threadpool_size_change() {
+    let mut c = default_population_configuration();
+    c.seed = Some(3);
+    let p = Population::<u8>::new(c).unwrap().evolve();
+    let mut p2 = p.clone();
+    p2.configuration.threadpool_size = 1;
+    assert_eq!(p.evolve().individuals_and_scores,
+               p2.evolve().individuals_and_scores);
+}
+
+#[test]
+fn multi_objective_evolve() {
+    let mut c = default_population_configuration();
+    c.objectives = Some(Arc::new(|i: &Individual<u8>| {
+        vec![i.genes[0] as f64, -(i.genes[0] as f64)]
+    }));
+    let mut p = Population::<u8>::new(c).unwrap();
+    for _ in 0..3 {
+        p = p.evolve();
+    }
+    assert_eq!(p.individuals_and_scores.len(), 64);
+    assert_eq!(p.get_pareto_front().len(), 64);
+    p.configuration.objectives = None;
+    let best = p.get_fittest().unwrap().1 as f64;
+    assert!(p.get_pareto_front().iter().all(|a| a.1 == vec![best]));
+}
+
+#[test]
+fn multi_objective_evaluations() {
+    let calls = Arc::new(::std::sync::atomic::AtomicUsize::new(0));
+    let counter = calls.clone();
+    let mut c = default_population_configuration();
+    c.objectives = Some(Arc::new(move |i: &Individual<u8>| {
+        counter.fetch_add(1, ::std::sync::atomic::Ordering::SeqCst);
+        vec![i.genes[0] as f64, -(i.genes[0] as f64)]
+    }));
+    let mut p = Population::<u8>::new(c).unwrap();
+    let count = |calls: &Arc<::std::sync::atomic::AtomicUsize>| {
+        calls.load(::std::sync::atomic::Ordering::SeqCst)
+    };
+    p = p.evolve();
+    let first = count(&calls);
+    assert_eq!(first, 64 + 64);
+    assert!(p.evaluations >= 64 + first + 64);
+    p.get_pareto_front();
+    assert_eq!(count(&calls), first);
+    let evaluations = p.evaluations;
+    p = p.evolve();
+    let second = count(&calls) - first;
+    assert!(second < 64 + 64);
+    assert!(p.evaluations - evaluations >= second + 64);
+}
+
+#[test]
+fn minimise_direction() {
+    let mut c = default_population_configuration();
+    c.direction = Direction::Minimise;
+    c.seed = Some(5);
+    let mut p = Population::<u8>::new(c).unwrap();
+    let first = p.get_fittest().unwrap().1;
+    assert!(p.individuals_and_scores.iter().all(|a| a.1 >= first));
+    for _ in 0..5 {
+        p = p.evolve();
+    }
+    assert!(p.get_fittest().unwrap().1 <= first);
+}
+
+#[test]
+fn float_score() {
+    let fitness = |i: &Individual<u8>| {
+        OrdF64(i.genes.iter().map(|g| *g as f64).sum::<f64>() / 8f64)
+    };
+    let c = configuration_builder(fitness, 64, 8, 2)
+        .mutation_rate(0.5f32)
+        .selection(Tournament::new(16))
+        .elitism_size(2)
+        .threadpool_size(8)
+        .build()
+        .unwrap();
+    let p = Population::new(c).unwrap();
+    let pe = p.evolve().evolve();
+    assert!(pe.get_fittest().unwrap().1 >= p.get_fittest().unwrap().1);
+}
+
+#[test]
+fn batch_fitness_once_per_generation() {
+    let batches = Arc::new(::std::sync::Mutex::new(Vec::new()));
+    let shared_batches = batches.clone();
+    let c = PopulationConfiguration::builder(simple_fitness)
+        .population_size(32)
+        .genenumber(8)
+        .elitism_size(2)
+        .batch_fitness(move |individuals: &[Individual<u8>]| {
+            shared_batches.lock().unwrap().push(individuals.len());
+            individuals.iter().map(simple_fitness).collect()
+        })
+        .threadpool_size(4)
+        .build()
+        .unwrap();
+    let mut p = Population::new(c).unwrap();
+    for _ in 0..3 {
+        p = p.evolve();
+        assert!(p.individuals_and_scores.iter().all(|a| simple_fitness(&a.0) == a.1));
+    }
+    assert_eq!(*batches.lock().unwrap(), vec![32, 30, 30, 30]);
+    assert_eq!(p.evaluations, 32 + 3 * 30);
+}
+
+#[test]
+fn batch_fitness_adapter() {
+    let batch = batch_fitness(Arc::new(simple_fitness) as Fitness<u8>);
+    let individuals = vec![Individual::new_with_vec(vec![0u8, 200]),
+                           Individual::new_with_vec(vec![255u8, 200]),
+                           Individual::new_with_vec(vec![0u8, 0])];
+    assert_eq!(batch(&individuals), vec![1, 2, 0]);
+}
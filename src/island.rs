@@ -0,0 +1,254 @@
+extern crate rand;
+use rand::Rng;
+
+use std::hash::Hash;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use genetic::{Evolution, Individual, Population, seeded_rng};
+use score::{Direction, Score};
+use statistics::Statistics;
+use observer::{Control, Event, Observer};
+use error::{ConfigurationError, Error, check};
+
+/// Topology of the migrations between the islands of an Archipelago
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Topology {
+    /// Each island sends its migrants to the next island
+    Ring,
+    /// Each island sends its migrants to every other island
+    FullyConnected,
+    /// Each island sends its migrants to an other random island
+    Random,
+}
+
+/// Contains the configuration of the migrations of an Archipelago
+#[derive(Debug,Clone)]
+pub struct ArchipelagoConfiguration {
+    /// Number of generations between two migrations, 0 disables the migrations
+    pub migration_interval: usize,
+    /// Number of the best Individuals of an island sent to each destination
+    pub migration_size: usize,
+    /// Destinations of the migrants of each island
+    pub topology: Topology,
+    /// Seed used by the Random topology, if None the destinations
+    /// can be different at each run
+    pub seed: Option<u64>,
+}
+
+/// A set of Populations which evolve concurrently, each Population is an
+/// island with its own PopulationConfiguration. Periodically the best
+/// Individuals migrate to the other islands and replace their worst Individuals
+#[derive(Clone)]
+pub struct Archipelago<T: 'static, S: 'static = i32> {
+    /// Populations of the islands
+    pub islands: Vec<Population<T, S>>,
+    /// Contains the configuration of the migrations
+    pub configuration: ArchipelagoConfiguration,
+    /// Number of the generations
+    pub generation: usize,
+    /// Wall time used to create the generation
+    elapsed: Duration,
+}
+
+impl<T, S> Archipelago<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    /// Create a new Archipelago from the islands, return an
+    /// error if the islands have different directions
+    pub fn new(islands: Vec<Population<T, S>>,
+               configuration: ArchipelagoConfiguration)
+               -> Result<Archipelago<T, S>, Error> {
+        let mut problems = Vec::new();
+        if let Some(first) = islands.first() {
+            let direction = first.configuration.direction;
+            if islands.iter().any(|island| island.configuration.direction != direction) {
+                problems.push(ConfigurationError::DifferentDirections);
+            }
+        }
+        check(problems)?;
+        Ok(Archipelago {
+            islands,
+            configuration,
+            generation: 0,
+            elapsed: Duration::new(0, 0),
+        })
+    }
+
+    /// Get the Individual with the best score of all the islands, the scores
+    /// are compared with the direction shared by the islands
+    pub fn get_fittest(&self) -> Option<(Individual<T>, S)> {
+        let direction = match self.islands.first() {
+            Some(island) => island.configuration.direction,
+            None => return None,
+        };
+        self.islands
+            .iter()
+            .filter_map(|island| island.get_fittest())
+            .max_by(|a, b| direction.compare(&a.1, &b.1))
+    }
+
+    /// Get the islands which receive the migrants of each island
+    fn get_destinations(&self) -> Vec<Vec<usize>> {
+        let n = self.islands.len();
+        let mut rng = match self.configuration.seed {
+            Some(seed) => seeded_rng(seed, self.generation as u64),
+            None => rand::weak_rng(),
+        };
+        (0..n)
+            .map(|i| match self.configuration.topology {
+                Topology::Ring => vec![(i + 1) % n],
+                Topology::FullyConnected => (0..n).filter(|j| *j != i).collect(),
+                Topology::Random => {
+                    let j = rng.gen_range(0, n - 1);
+                    vec![if j >= i { j + 1 } else { j }]
+                }
+            })
+            .collect()
+    }
+
+    /// Send the best Individuals of each island to its destinations
+    fn migrate(&mut self) {
+        if self.islands.len() < 2 || self.configuration.migration_size == 0 {
+            return;
+        }
+        let mut immigrants: Vec<Vec<Individual<T>>> = vec![Vec::new(); self.islands.len()];
+        for (i, destinations) in self.get_destinations().into_iter().enumerate() {
+            let migrants: Vec<Individual<T>> = self.islands[i]
+                .get_top(self.configuration.migration_size)
+                .into_iter()
+                .map(|a| a.0)
+                .collect();
+            for d in destinations {
+                immigrants[d].extend(migrants.iter().cloned());
+            }
+        }
+        for (island, individuals) in self.islands.iter_mut().zip(immigrants) {
+            *island = island.replace_worst(individuals);
+        }
+    }
+
+    /// Create the next generation of each island concurrently,
+    /// followed by the migrations if it is the time
+    pub fn evolve(&self) -> Archipelago<T, S> {
+        let start = Instant::now();
+        let islands: Vec<Population<T, S>> = thread::scope(|s| {
+            let handles: Vec<_> = self.islands
+                .iter()
+                .map(|island| s.spawn(move || island.evolve()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        let mut archipelago = Archipelago {
+            islands,
+            configuration: self.configuration.clone(),
+            generation: self.generation + 1,
+            elapsed: Duration::new(0, 0),
+        };
+        let interval = self.configuration.migration_interval;
+        if interval > 0 && archipelago.generation % interval == 0 {
+            archipelago.migrate();
+        }
+        archipelago.elapsed = start.elapsed();
+        archipelago
+    }
+
+    /// Notify the event once to each Observer of the islands,
+    /// return true if one of them has asked to stop
+    pub fn notify(&self, event: &Event) -> bool {
+        let mut observers: Vec<&Arc<dyn Observer>> = Vec::new();
+        for island in &self.islands {
+            for o in &island.configuration.observers {
+                let pointer = Arc::as_ptr(o) as *const u8;
+                if observers.iter().all(|a| Arc::as_ptr(a) as *const u8 != pointer) {
+                    observers.push(o);
+                }
+            }
+        }
+        observers.iter()
+            .map(|o| o.notify(event))
+            .collect::<Vec<Control>>()
+            .contains(&Control::Stop)
+    }
+
+    /// Get the Individuals of all the islands
+    fn get_individuals(&self) -> Vec<(Individual<T>, S)> {
+        self.islands
+            .iter()
+            .flat_map(|island| island.individuals_and_scores.iter().cloned())
+            .collect()
+    }
+
+    /// Get the Statistics of the Individuals of all the islands, the
+    /// rates are the mean of the rates of the islands
+    pub fn get_statistics(&self) -> Statistics {
+        let direction = Evolution::get_direction(self);
+        let individuals = self.get_individuals();
+        let n = self.islands.len().max(1) as f32;
+        Statistics {
+            generation: self.generation,
+            evaluations: self.islands.iter().map(|island| island.evaluations).sum(),
+            elapsed: self.elapsed,
+            mutation_rate: self.islands
+                .iter()
+                .map(|island| island.configuration.mutation_rate)
+                .sum::<f32>() / n,
+            uniform_rate: self.islands
+                .iter()
+                .map(|island| island.configuration.uniform_rate)
+                .sum::<f32>() / n,
+            ..Statistics::new(&individuals, direction)
+        }
+    }
+}
+
+impl<T, S> Archipelago<T, S>
+    where T: Clone + Send + Sync + Hash + Eq + 'static,
+          S: Score
+{
+    /// Get the Statistics of the Individuals of all the islands
+    /// with the statistics of the genomes
+    pub fn get_statistics_with_genomes(&self) -> Statistics {
+        self.get_statistics().with_genomes(&self.get_individuals())
+    }
+}
+
+impl<T, S> Evolution<T, S> for Archipelago<T, S>
+    where T: Clone + Send + Sync + PartialEq + 'static,
+          S: Score
+{
+    fn evolve(&self) -> Archipelago<T, S> {
+        Archipelago::evolve(self)
+    }
+
+    fn get_fittest(&self) -> Option<(Individual<T>, S)> {
+        Archipelago::get_fittest(self)
+    }
+
+    fn get_statistics(&self) -> Statistics {
+        Archipelago::get_statistics(self)
+    }
+
+    fn get_statistics_with_genomes(&self) -> Statistics
+        where T: Hash + Eq
+    {
+        Archipelago::get_statistics_with_genomes(self)
+    }
+
+    fn notify(&self, event: &Event) -> bool {
+        Archipelago::notify(self, event)
+    }
+
+    fn is_stop_requested(&self) -> bool {
+        self.islands.iter().any(|island| island.stop_requested)
+    }
+
+    fn get_direction(&self) -> Direction {
+        match self.islands.first() {
+            Some(island) => island.configuration.direction,
+            None => Direction::Maximise,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use genetic::*;
+use island::*;
+use test_helpers::configuration_builder;
+use score::Direction;
+use error::{ConfigurationError, Error};
+
+#[allow(dead_code)]
+fn island(target: u8, seed: u64) -> Population<u8> {
+    let fitness = move |i: &Individual<u8>| i.genes.iter().filter(|g| **g == target).count() as i32;
+    let c = configuration_builder(fitness, 16, 4, seed)
+        .mutation_rate(0.2f32)
+        .threadpool_size(1)
+        .build()
+        .unwrap();
+    Population::new(c).unwrap()
+}
+
+#[allow(dead_code)]
+fn archipelago_configuration(topology: Topology) -> ArchipelagoConfiguration {
+    ArchipelagoConfiguration {
+        migration_interval: 2,
+        migration_size: 2,
+        topology,
+        seed: Some(9),
+    }
+}
+
+#[test]
+fn evolve_islands() {
+    let islands = vec![island(1, 1), island(1, 2), island(2, 3)];
+    let mut a = Archipelago::new(islands, archipelago_configuration(Topology::Random)).unwrap();
+    let first = a.get_fittest().unwrap().1;
+    for _ in 0..6 {
+        a = a.evolve();
+    }
+    assert_eq!(a.generation, 6);
+    assert!(a.islands.iter().all(|i| i.generation == 6 && i.individuals_and_scores.len() == 16));
+    assert!(a.get_fittest().unwrap().1 >= first);
+}
+
+#[test]
+fn migration_rescores() {
+    let mut best = island(1, 1);
+    best.individuals_and_scores[0] = (Individual::new_with_vec(vec![1u8; 4]), 4);
+    let islands = vec![best, island(2, 2)];
+    let mut c = archipelago_configuration(Topology::FullyConnected);
+    c.migration_interval = 1;
+    let a = Archipelago::new(islands, c).unwrap().evolve();
+    let migrant = Individual::new_with_vec(vec![1u8; 4]);
+    let score = a.islands[1].individuals_and_scores.iter().find(|s| s.0 == migrant).unwrap().1;
+    assert_eq!(score, 0);
+}
+
+#[test]
+fn same_seed_same_archipelago() {
+    let create = || {
+        Archipelago::new(vec![island(1, 1), island(1, 2), island(1, 3)],
+                         archipelago_configuration(Topology::Random))
+            .unwrap()
+    };
+    let mut a1 = create();
+    let mut a2 = create();
+    for _ in 0..4 {
+        a1 = a1.evolve();
+        a2 = a2.evolve();
+    }
+    for (i1, i2) in a1.islands.iter().zip(a2.islands.iter()) {
+        assert_eq!(i1.individuals_and_scores, i2.individuals_and_scores);
+    }
+}
+
+#[test]
+fn different_directions() {
+    let mut minimise = island(1, 2);
+    minimise.configuration.direction = Direction::Minimise;
+    let islands = vec![island(1, 1), minimise];
+    match Archipelago::new(islands, archipelago_configuration(Topology::Ring)) {
+        Err(Error::InvalidConfiguration(problems)) => {
+            assert_eq!(problems, vec![ConfigurationError::DifferentDirections])
+        }
+        _ => panic!("the islands have different directions"),
+    }
+}
@@ -2,8 +2,67 @@
 extern crate rand;
 extern crate threadpool;
 
+pub mod error;
+mod error_test;
+
+pub mod generator;
+mod generator_test;
+
 pub mod genetic;
 mod genetic_test;
 
+pub mod score;
+mod score_test;
+
+pub mod selection;
+mod selection_test;
+
+pub mod crossover;
+mod crossover_test;
+
+pub mod mutation;
+mod mutation_test;
+
+pub mod replacement;
+mod replacement_test;
+
+pub mod adaptation;
+mod adaptation_test;
+
+pub mod niching;
+mod niching_test;
+
+pub mod restart;
+mod restart_test;
+
+pub mod memetic;
+mod memetic_test;
+
+pub mod statistics;
+mod statistics_test;
+
+pub mod observer;
+mod observer_test;
+
+pub mod termination;
+mod termination_test;
+
+pub mod cache;
+mod cache_test;
+
+pub mod pareto;
+mod pareto_test;
+
+pub mod island;
+mod island_test;
+
+pub mod checkpoint;
+mod checkpoint_test;
+
+pub mod builder;
+mod builder_test;
+
 pub mod genetic_planner;
 mod genetic_planner_test;
+
+mod test_helpers;
@@ -0,0 +1,177 @@
+extern crate rand;
+use rand::{Rng, XorShiftRng};
+
+use std::sync::Arc;
+
+use genetic::Individual;
+use generator::{GeneContext, GeneGenerator};
+use score::{Direction, Score};
+
+/// What the Population keeps of the result of a local search
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Inheritance {
+    /// The improved genome and its score replace the offspring
+    Lamarckian,
+    /// The offspring keeps its genome but gets the improved score
+    Baldwinian,
+}
+
+/// Access to the fitness given to a LocalSearch, which counts the evaluations
+/// and refuses to evaluate more Individuals than the budget
+pub struct SearchContext<'a, T: 'static, S: 'a> {
+    fitness: &'a dyn Fn(&Individual<T>) -> S,
+    generator: &'a dyn GeneGenerator<T>,
+    direction: Direction,
+    budget: usize,
+    evaluations: usize,
+}
+
+impl<'a, T, S> SearchContext<'a, T, S> {
+    /// Create a new SearchContext which evaluates at most budget Individuals
+    pub fn new(fitness: &'a dyn Fn(&Individual<T>) -> S,
+               generator: &'a dyn GeneGenerator<T>,
+               direction: Direction,
+               budget: usize)
+               -> SearchContext<'a, T, S> {
+        SearchContext {
+            fitness,
+            generator,
+            direction,
+            budget,
+            evaluations: 0,
+        }
+    }
+
+    /// Calculate the score of the Individual, None if the budget is exhausted
+    pub fn evaluate(&mut self, i: &Individual<T>) -> Option<S> {
+        if self.evaluations >= self.budget {
+            return None;
+        }
+        self.evaluations += 1;
+        Some((self.fitness)(i))
+    }
+
+    /// Get the number of evaluations left in the budget
+    pub fn remaining(&self) -> usize {
+        self.budget - self.evaluations
+    }
+
+    /// Get the number of Individuals evaluated
+    pub fn evaluations(&self) -> usize {
+        self.evaluations
+    }
+
+    /// Get the generator of the genes of the configuration
+    pub fn generator(&self) -> &dyn GeneGenerator<T> {
+        self.generator
+    }
+
+    /// Get the direction of the optimisation of the score
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+}
+
+/// Operator used to improve an offspring before it enters the Population
+pub trait LocalSearch<T, S>: Send + Sync {
+    /// Get an Individual at least as good as i, which has the given score,
+    /// and its score. The Individuals are evaluated through the context
+    fn improve(&self,
+               i: &Individual<T>,
+               score: &S,
+               context: &mut SearchContext<T, S>,
+               rng: &mut XorShiftRng)
+               -> (Individual<T>, S);
+}
+
+impl<T, S, F> LocalSearch<T, S> for F
+    where T: 'static,
+          F: Fn(&Individual<T>, &S, &mut SearchContext<T, S>, &mut XorShiftRng)
+                -> (Individual<T>, S) + Send + Sync
+{
+    fn improve(&self,
+               i: &Individual<T>,
+               score: &S,
+               context: &mut SearchContext<T, S>,
+               rng: &mut XorShiftRng)
+               -> (Individual<T>, S) {
+        self(i, score, context, rng)
+    }
+}
+
+/// Hill climbing over single gene changes: a random gene is replaced by a new gene
+/// of the generator and the change is kept if the score is better, until the budget
+/// is exhausted
+#[derive(Debug,Clone,Copy)]
+pub struct HillClimbing;
+
+impl<T, S> LocalSearch<T, S> for HillClimbing
+    where T: Clone,
+          S: Score
+{
+    fn improve(&self,
+               i: &Individual<T>,
+               score: &S,
+               context: &mut SearchContext<T, S>,
+               rng: &mut XorShiftRng)
+               -> (Individual<T>, S) {
+        let mut best = (i.clone(), score.clone());
+        if i.genes.is_empty() {
+            return best;
+        }
+        while context.remaining() > 0 {
+            let mut neighbour = best.0.clone();
+            let gene_context = GeneContext {
+                position: rng.gen_range(0, neighbour.genes.len()),
+                genenumber: neighbour.genes.len(),
+            };
+            neighbour.genes[gene_context.position] =
+                context.generator().generate(&gene_context, rng);
+            if let Some(s) = context.evaluate(&neighbour) {
+                if context.direction().is_better(&s, &best.1) {
+                    best = (neighbour, s);
+                }
+            }
+        }
+        best
+    }
+}
+
+/// Local improvement of the offsprings created by the evolve function
+#[derive(Clone)]
+pub struct Memetic<T: 'static, S: 'static> {
+    /// Operator used to improve the offsprings
+    pub local_search: Arc<dyn LocalSearch<T, S>>,
+    /// Fraction of the offsprings improved, between 0 and 1
+    pub rate: f32,
+    /// Max number of evaluations of the local search of each offspring
+    pub budget: usize,
+    /// What the Population keeps of the improvement
+    pub inheritance: Inheritance,
+}
+
+impl<T, S> Memetic<T, S> {
+    /// Create a new Memetic which writes back the improved genomes
+    pub fn lamarckian<L>(local_search: L, rate: f32, budget: usize) -> Memetic<T, S>
+        where L: LocalSearch<T, S> + 'static
+    {
+        Memetic {
+            local_search: Arc::new(local_search),
+            rate,
+            budget,
+            inheritance: Inheritance::Lamarckian,
+        }
+    }
+
+    /// Create a new Memetic which keeps only the improved scores
+    pub fn baldwinian<L>(local_search: L, rate: f32, budget: usize) -> Memetic<T, S>
+        where L: LocalSearch<T, S> + 'static
+    {
+        Memetic {
+            local_search: Arc::new(local_search),
+            rate,
+            budget,
+            inheritance: Inheritance::Baldwinian,
+        }
+    }
+}
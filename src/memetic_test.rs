@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+extern crate rand;
+use rand::{Rng, XorShiftRng};
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use genetic::*;
+use memetic::*;
+use generator::GeneContext;
+use score::Direction;
+use selection::Tournament;
+
+#[allow(dead_code)]
+fn ones(i: &Individual<u8>) -> i32 {
+    i.genes.iter().filter(|g| **g == 1).count() as i32
+}
+
+#[allow(dead_code)]
+fn configuration(memetic: Memetic<u8, i32>,
+                 calls: Arc<AtomicUsize>)
+                 -> PopulationConfiguration<u8> {
+    let fitness = move |i: &Individual<u8>| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        ones(i)
+    };
+    let generator = |_: &GeneContext, r: &mut XorShiftRng| r.gen_range(0u8, 4);
+    PopulationConfiguration::builder_with_generator(fitness, generator)
+        .population_size(32)
+        .genenumber(16)
+        .selection(Tournament::new(2))
+        .elitism_size(2)
+        .memetic(memetic)
+        .threadpool_size(2)
+        .seed(5)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn hill_climbing_budget() {
+    let i = Individual::new_with_vec(vec![0u8; 8]);
+    let generator = |_: &GeneContext, _: &mut XorShiftRng| 1u8;
+    let fitness = |i: &Individual<u8>| ones(i);
+    let mut context = SearchContext::new(&fitness, &generator, Direction::Maximise, 5);
+    let (improved, score) = HillClimbing.improve(&i, &0, &mut context, &mut seeded_rng(1, 0));
+    assert_eq!(context.evaluations(), 5);
+    assert_eq!(context.remaining(), 0);
+    assert!(score > 0 && score <= 5);
+    assert_eq!(ones(&improved), score);
+    assert_eq!(context.evaluate(&improved), None);
+}
+
+#[test]
+fn lamarckian() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let memetic = Memetic::lamarckian(HillClimbing, 1f32, 4);
+    let mut p = Population::new(configuration(memetic, calls.clone())).unwrap();
+    for _ in 0..3 {
+        p = p.evolve();
+    }
+    assert_eq!(p.evaluations, 32 + 3 * 30 * 5);
+    assert_eq!(calls.load(Ordering::SeqCst), p.evaluations);
+    assert!(p.individuals_and_scores.iter().all(|a| ones(&a.0) == a.1));
+}
+
+#[test]
+fn baldwinian() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let memetic = Memetic::baldwinian(HillClimbing, 1f32, 4);
+    let mut p = Population::new(configuration(memetic, calls.clone())).unwrap();
+    for _ in 0..3 {
+        p = p.evolve();
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), p.evaluations);
+    assert!(p.individuals_and_scores.iter().all(|a| ones(&a.0) <= a.1));
+    assert!(p.individuals_and_scores.iter().any(|a| ones(&a.0) < a.1));
+}
+
+#[test]
+fn local_search_closure() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let ones_everywhere = |i: &Individual<u8>,
+                           s: &i32,
+                           context: &mut SearchContext<u8, i32>,
+                           _: &mut XorShiftRng| {
+        let candidate = Individual::new_with_vec(vec![1u8; i.genes.len()]);
+        match context.evaluate(&candidate) {
+            Some(score) => (candidate, score),
+            None => (i.clone(), *s),
+        }
+    };
+    let memetic = Memetic::lamarckian(ones_everywhere, 0.5f32, 1);
+    let p = Population::new(configuration(memetic, calls)).unwrap().evolve();
+    assert_eq!(p.get_fittest().unwrap().1, 16);
+    assert!(p.evaluations > 32 + 30 && p.evaluations < 32 + 2 * 30);
+}
+
+#[test]
+fn invalid_rate() {
+    let memetic = Memetic::lamarckian(HillClimbing, -0.5f32, 10);
+    let result = PopulationConfiguration::builder(|i: &Individual<u8>| ones(i))
+        .memetic(memetic)
+        .build();
+    assert!(result.is_err());
+}
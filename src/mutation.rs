@@ -0,0 +1,304 @@
+extern crate rand;
+use rand::{Rng, XorShiftRng};
+
+use std::sync::Arc;
+
+use genetic::Individual;
+use error::ConfigurationError;
+use generator::{GeneContext, GeneGenerator};
+
+/// Operator used to change the genes of an offspring
+pub trait Mutation<T>: Send + Sync {
+    /// Return the mutated copy of i, rate is the mutation parameter
+    /// of the configuration and generator creates the new genes
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              generator: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T>;
+
+    /// Get the problem of the parameters of the Mutation, if any
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        Ok(())
+    }
+}
+
+/// Verify if an operator with probability rate has to be applied
+fn happens(rate: f32, rng: &mut XorShiftRng) -> bool {
+    rng.gen_range(0f32, 1f32) < rate
+}
+
+/// Get two random positions a < b of a genome of len genes
+fn positions(len: usize, rng: &mut XorShiftRng) -> (usize, usize) {
+    let a = rng.gen_range(0, len);
+    let mut b = rng.gen_range(0, len - 1);
+    if b >= a {
+        b += 1;
+    }
+    if a < b { (a, b) } else { (b, a) }
+}
+
+/// Replace each gene, with probability rate, with a new gene of the generator
+#[derive(Debug,Clone)]
+pub struct RandomResetting;
+
+impl<T> Mutation<T> for RandomResetting
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              generator: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let genenumber = i.genes.len();
+        let genes = i.genes
+            .iter()
+            .enumerate()
+            .map(|(position, g)| if happens(rate, rng) {
+                generator.generate(&GeneContext { position, genenumber }, rng)
+            } else {
+                g.clone()
+            })
+            .collect();
+        Individual { genes }
+    }
+}
+
+/// Exchange two random genes, with probability rate
+#[derive(Debug,Clone)]
+pub struct SwapMutation;
+
+impl<T> Mutation<T> for SwapMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if genes.len() > 1 && happens(rate, rng) {
+            let (a, b) = positions(genes.len(), rng);
+            genes.swap(a, b);
+        }
+        Individual { genes }
+    }
+}
+
+/// Move a random gene to another position, with probability rate
+#[derive(Debug,Clone)]
+pub struct InsertionMutation;
+
+impl<T> Mutation<T> for InsertionMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if genes.len() > 1 && happens(rate, rng) {
+            let from = rng.gen_range(0, genes.len());
+            let g = genes.remove(from);
+            let to = rng.gen_range(0, genes.len() + 1);
+            genes.insert(to, g);
+        }
+        Individual { genes }
+    }
+}
+
+/// Reverse the order of a random segment of genes, with probability rate
+#[derive(Debug,Clone)]
+pub struct InversionMutation;
+
+impl<T> Mutation<T> for InversionMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if genes.len() > 1 && happens(rate, rng) {
+            let (a, b) = positions(genes.len(), rng);
+            genes[a..b + 1].reverse();
+        }
+        Individual { genes }
+    }
+}
+
+/// Shuffle a random segment of genes, with probability rate
+#[derive(Debug,Clone)]
+pub struct ScrambleMutation;
+
+impl<T> Mutation<T> for ScrambleMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if genes.len() > 1 && happens(rate, rng) {
+            let (a, b) = positions(genes.len(), rng);
+            rng.shuffle(&mut genes[a..b + 1]);
+        }
+        Individual { genes }
+    }
+}
+
+/// Insert a new gene of the generator in a random position, with probability rate
+#[derive(Debug,Clone)]
+pub struct GeneInsertionMutation;
+
+impl<T> Mutation<T> for GeneInsertionMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              generator: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if happens(rate, rng) {
+            let position = rng.gen_range(0, genes.len() + 1);
+            let context = GeneContext {
+                position,
+                genenumber: genes.len() + 1,
+            };
+            genes.insert(position, generator.generate(&context, rng));
+        }
+        Individual { genes }
+    }
+}
+
+/// Remove a random gene, with probability rate
+#[derive(Debug,Clone)]
+pub struct GeneDeletionMutation;
+
+impl<T> Mutation<T> for GeneDeletionMutation
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let mut genes = i.genes.clone();
+        if !genes.is_empty() && happens(rate, rng) {
+            let position = rng.gen_range(0, genes.len());
+            genes.remove(position);
+        }
+        Individual { genes }
+    }
+}
+
+/// Function used to perturb a single gene
+pub type Perturbation<T> = Arc<dyn Fn(&T, &mut XorShiftRng) -> T + Send + Sync>;
+
+/// Replace each gene, with probability rate, with the result of a user
+/// supplied perturbation of the gene
+#[derive(Clone)]
+pub struct GenePerturbation<T> {
+    /// Function applied to the mutated genes
+    pub perturbation: Perturbation<T>,
+}
+
+impl<T> GenePerturbation<T> {
+    /// Create a new GenePerturbation from a perturbation function
+    pub fn new(perturbation: Perturbation<T>) -> GenePerturbation<T> {
+        GenePerturbation { perturbation }
+    }
+}
+
+impl<T> Mutation<T> for GenePerturbation<T>
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              _: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let genes = i.genes
+            .iter()
+            .map(|g| if happens(rate, rng) {
+                (self.perturbation)(g, rng)
+            } else {
+                g.clone()
+            })
+            .collect();
+        Individual { genes }
+    }
+}
+
+/// Apply to each offspring one of several operators,
+/// chosen with a probability proportional to its weight
+#[derive(Clone)]
+pub struct WeightedMutation<T> {
+    /// Operators and relative weights
+    pub operators: Vec<(f64, Arc<dyn Mutation<T>>)>,
+}
+
+impl<T> WeightedMutation<T> {
+    /// Create a new WeightedMutation without operators
+    pub fn new() -> WeightedMutation<T> {
+        WeightedMutation { operators: Vec::new() }
+    }
+
+    /// Return the WeightedMutation with an other operator
+    pub fn add<M>(mut self, weight: f64, operator: M) -> WeightedMutation<T>
+        where M: Mutation<T> + 'static
+    {
+        self.operators.push((weight, Arc::new(operator)));
+        self
+    }
+}
+
+impl<T> Default for WeightedMutation<T> {
+    fn default() -> WeightedMutation<T> {
+        WeightedMutation::new()
+    }
+}
+
+impl<T> Mutation<T> for WeightedMutation<T>
+    where T: Clone + Send + Sync
+{
+    fn mutate(&self,
+              i: &Individual<T>,
+              rate: f32,
+              generator: &dyn GeneGenerator<T>,
+              rng: &mut XorShiftRng)
+              -> Individual<T> {
+        let total: f64 = self.operators.iter().map(|o| o.0).sum();
+        if total <= 0f64 {
+            return i.clone();
+        }
+        let mut point = rng.gen_range(0f64, total);
+        for &(weight, ref operator) in &self.operators {
+            if point < weight {
+                return operator.mutate(i, rate, generator, rng);
+            }
+            point -= weight;
+        }
+        self.operators.last().unwrap().1.mutate(i, rate, generator, rng)
+    }
+
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        match self.operators.iter().find(|o| !o.0.is_finite() || o.0 < 0f64) {
+            Some(&(weight, _)) => Err(ConfigurationError::InvalidWeight(weight)),
+            None => self.operators.iter().try_for_each(|o| o.1.validate()),
+        }
+    }
+}
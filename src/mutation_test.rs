@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+extern crate rand;
+use rand::{SeedableRng, XorShiftRng};
+
+use std::sync::Arc;
+
+use genetic::Individual;
+use mutation::*;
+use generator::RandomGenes;
+
+#[allow(dead_code)]
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[allow(dead_code)]
+fn permutation() -> Individual<u8> {
+    Individual::new_with_vec(vec![0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+}
+
+#[test]
+fn permutation_mutations() {
+    let mutations: Vec<Box<dyn Mutation<u8>>> = vec![Box::new(SwapMutation),
+                                                     Box::new(InsertionMutation),
+                                                     Box::new(InversionMutation),
+                                                     Box::new(ScrambleMutation)];
+    let mut r = rng();
+    for m in mutations {
+        for _ in 0..20 {
+            let i = m.mutate(&permutation(), 1f32, &RandomGenes, &mut r);
+            let mut genes = i.genes.clone();
+            genes.sort();
+            assert_eq!(genes, permutation().genes);
+        }
+        assert_eq!(m.mutate(&permutation(), 0f32, &RandomGenes, &mut r), permutation());
+    }
+}
+
+#[test]
+fn swap_mutation() {
+    let i = SwapMutation.mutate(&permutation(), 1f32, &RandomGenes, &mut rng());
+    let changed = i.genes.iter().zip(permutation().genes.iter()).filter(|&(a, b)| a != b).count();
+    assert_eq!(changed, 2);
+}
+
+#[test]
+fn gene_perturbation() {
+    let m = GenePerturbation::new(Arc::new(|g: &u8, _: &mut XorShiftRng| g + 10));
+    let i = m.mutate(&permutation(), 1f32, &RandomGenes, &mut rng());
+    assert_eq!(i.genes, vec![10u8, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+}
+
+#[test]
+fn weighted_mutation() {
+    let only_perturbation = WeightedMutation::new()
+        .add(0f64, SwapMutation)
+        .add(1f64, GenePerturbation::new(Arc::new(|_: &u8, _: &mut XorShiftRng| 0u8)));
+    let i = only_perturbation.mutate(&permutation(), 1f32, &RandomGenes, &mut rng());
+    assert_eq!(i.genes, vec![0u8; 10]);
+    let empty = WeightedMutation::<u8>::new();
+    assert_eq!(empty.mutate(&permutation(), 1f32, &RandomGenes, &mut rng()), permutation());
+}
+
+#[test]
+fn length_mutations() {
+    let mut r = rng();
+    let i = GeneInsertionMutation.mutate(&permutation(), 1f32, &RandomGenes, &mut r);
+    assert_eq!(i.genes.len(), 11);
+    let d = GeneDeletionMutation.mutate(&permutation(), 1f32, &RandomGenes, &mut r);
+    assert_eq!(d.genes.len(), 9);
+    let empty = Individual::<u8>::new_with_vec(Vec::new());
+    assert_eq!(GeneDeletionMutation.mutate(&empty, 1f32, &RandomGenes, &mut r), empty);
+}
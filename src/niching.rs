@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use genetic::Individual;
+
+/// Function used to calculate the distance between the genomes of two Individuals
+pub type Distance<T> = Arc<dyn Fn(&Individual<T>, &Individual<T>) -> f64 + Send + Sync>;
+
+/// Hamming distance between two genomes: the number of different genes
+/// in the same position, plus the difference of the lengths
+pub fn hamming<T: PartialEq>(a: &Individual<T>, b: &Individual<T>) -> f64 {
+    let different = a.genes.iter().zip(b.genes.iter()).filter(|&(x, y)| x != y).count();
+    let longest = a.genes.len().max(b.genes.len());
+    let shortest = a.genes.len().min(b.genes.len());
+    (different + longest - shortest) as f64
+}
+
+/// Strategy used to keep different niches of Individuals in the Population
+#[derive(Clone)]
+pub enum Niching<T: 'static> {
+    /// The score used by the selection is divided by the number of Individuals in
+    /// the niche: each Individual at distance d lower than radius counts 1 - (d/radius)^alpha
+    FitnessSharing {
+        /// Distance within which two Individuals share the score
+        radius: f64,
+        /// Shape of the sharing function, 1 is linear
+        alpha: f64,
+        /// Distance between the genomes
+        distance: Distance<T>,
+    },
+    /// The parents are paired randomly and each offspring replaces
+    /// the most similar parent if it is not worse than it.
+    /// It replaces the Replacement of the configuration
+    DeterministicCrowding {
+        /// Distance between the genomes
+        distance: Distance<T>,
+    },
+}
+
+impl<T> Niching<T>
+    where T: PartialEq
+{
+    /// Create a new FitnessSharing with the Hamming distance
+    pub fn fitness_sharing(radius: f64, alpha: f64) -> Niching<T> {
+        Niching::FitnessSharing {
+            radius,
+            alpha,
+            distance: Arc::new(hamming),
+        }
+    }
+
+    /// Create a new DeterministicCrowding with the Hamming distance
+    pub fn deterministic_crowding() -> Niching<T> {
+        Niching::DeterministicCrowding { distance: Arc::new(hamming) }
+    }
+}
+
+/// Get the shared values: the values, shifted so that the worst one is 0,
+/// divided by the niche count of each Individual
+pub fn share<T>(values: &[f64],
+                individuals: &[&Individual<T>],
+                radius: f64,
+                alpha: f64,
+                distance: &Distance<T>)
+                -> Vec<f64> {
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    (0..values.len())
+        .map(|i| {
+            let niche: f64 = individuals.iter()
+                .map(|other| {
+                    let d = distance(individuals[i], other);
+                    if d < radius { 1f64 - (d / radius).powf(alpha) } else { 0f64 }
+                })
+                .sum();
+            (values[i] - min) / niche.max(1f64)
+        })
+        .collect()
+}
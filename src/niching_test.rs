@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+
+use genetic::*;
+use niching::*;
+use selection::Tournament;
+use crossover::OnePointCrossover;
+use test_helpers::configuration_builder;
+
+#[allow(dead_code)]
+fn configuration(niching: Option<Niching<u8>>,
+                 eliminate_duplicates: bool)
+                 -> PopulationConfiguration<u8> {
+    let fitness = |i: &Individual<u8>| i.genes.iter().map(|g| (*g % 4) as i32).sum();
+    let mut c = configuration_builder(fitness, 32, 4, 8)
+        .mutation_rate(0.1f32)
+        .crossover(OnePointCrossover)
+        .selection(Tournament::new(4))
+        .elitism_size(2)
+        .eliminate_duplicates(eliminate_duplicates)
+        .build()
+        .unwrap();
+    c.niching = niching;
+    c
+}
+
+#[test]
+fn hamming_distance() {
+    let a = Individual::new_with_vec(vec![1u8, 2, 3, 4]);
+    let b = Individual::new_with_vec(vec![1u8, 0, 3, 0, 5]);
+    assert_eq!(hamming(&a, &a), 0f64);
+    assert_eq!(hamming(&a, &b), 3f64);
+    assert_eq!(hamming(&b, &a), 3f64);
+}
+
+#[test]
+fn shared_values() {
+    let a = Individual::new_with_vec(vec![1u8, 1]);
+    let b = Individual::new_with_vec(vec![1u8, 1]);
+    let c = Individual::new_with_vec(vec![2u8, 2]);
+    let distance: Distance<u8> = Arc::new(hamming);
+    let shared = share(&[3f64, 3f64, 3f64, 1f64], &[&a, &b, &c, &c], 1f64, 1f64, &distance);
+    assert_eq!(shared, vec![1f64, 1f64, 1f64, 0f64]);
+}
+
+#[test]
+fn no_duplicates() {
+    let mut p = Population::new(configuration(None, true)).unwrap();
+    for _ in 0..10 {
+        p = p.evolve();
+        assert_eq!(p.get_unique_genomes(), 32);
+    }
+}
+
+#[test]
+fn distinct_elites() {
+    let mut c = configuration(None, false);
+    c.elitism_size = 3;
+    let scored = |gene: u8| {
+        let i = Individual::new_with_vec(vec![gene; 4]);
+        let score = (c.fitness)(&i);
+        (i, score)
+    };
+    let mut v = vec![scored(3), scored(3), scored(3), scored(2), scored(1)];
+    v.extend((0..27).map(|_| scored(0)));
+    let p = Population::new_with_vec(v, c.clone(), 0).unwrap().evolve();
+    let elites: Vec<u8> = p.individuals_and_scores[..3].iter().map(|a| a.0.genes[0]).collect();
+    assert_eq!(elites, vec![3, 2, 1]);
+}
+
+#[test]
+fn fitness_sharing_evolve() {
+    let c = configuration(Some(Niching::fitness_sharing(2f64, 1f64)), false);
+    let mut p = Population::new(c).unwrap();
+    let best = p.get_fittest().unwrap().1;
+    for _ in 0..5 {
+        p = p.evolve();
+    }
+    assert!(p.get_fittest().unwrap().1 >= best);
+}
+
+#[test]
+fn deterministic_crowding() {
+    let c = configuration(Some(Niching::deterministic_crowding()), false);
+    let mut p = Population::new(c).unwrap();
+    let mut best = p.get_fittest().unwrap().1;
+    for _ in 0..10 {
+        p = p.evolve();
+        assert_eq!(p.individuals_and_scores.len(), 32);
+        assert!(p.get_fittest().unwrap().1 >= best);
+        best = p.get_fittest().unwrap().1;
+    }
+    assert!(p.get_unique_genomes() > 1);
+}
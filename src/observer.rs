@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use statistics::Statistics;
+
+/// Something which happened during a run
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum Event<'a> {
+    /// A new generation has been created
+    GenerationFinished(&'a Statistics),
+    /// The new generation has a better Individual than the previous one
+    NewBest(&'a Statistics),
+    /// The new generation has no better Individual than the previous one,
+    /// the number is the count of the generations without improvements
+    Stagnation(&'a Statistics, usize),
+    /// A catastrophe has replaced every Individual of the Population but the elites,
+    /// the Statistics are the ones of the Population after the restart
+    Restarted(&'a Statistics),
+    /// The planner has found a Plan which reaches the goal
+    GoalReached(&'a Statistics),
+}
+
+/// Answer of an Observer to an Event
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Control {
+    /// The run goes on
+    Continue,
+    /// The run has to stop as soon as possible, the planner
+    /// returns the best Plan found so far
+    Stop,
+}
+
+/// Receive the Events of the evolution of a Population and of the planner
+pub trait Observer: Send + Sync {
+    /// Called for each Event, the result can ask to stop the run
+    fn notify(&self, event: &Event) -> Control;
+}
+
+impl<F> Observer for F
+    where F: Fn(&Event) -> Control + Send + Sync
+{
+    fn notify(&self, event: &Event) -> Control {
+        self(event)
+    }
+}
+
+impl<O> Observer for Arc<O>
+    where O: Observer + ?Sized
+{
+    fn notify(&self, event: &Event) -> Control {
+        (**self).notify(event)
+    }
+}
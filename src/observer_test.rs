@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use genetic::*;
+use observer::*;
+use test_helpers::configuration_builder;
+
+#[allow(dead_code)]
+fn configuration(observers: Vec<Arc<dyn Observer>>) -> PopulationConfiguration<u8> {
+    let mut c = configuration_builder(|i: &Individual<u8>| (i.genes[0] / 64) as i32, 16, 2, 2)
+        .mutation_rate(0.3f32)
+        .build()
+        .unwrap();
+    c.observers = observers;
+    c
+}
+
+#[test]
+fn events() {
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let log = events.clone();
+    let observer = move |event: &Event| {
+        let name = match *event {
+            Event::GenerationFinished(s) => format!("finished {}", s.generation),
+            Event::NewBest(_) => "best".to_string(),
+            Event::Stagnation(_, n) => format!("stagnation {}", n),
+            Event::Restarted(_) => "restarted".to_string(),
+            Event::GoalReached(_) => "goal".to_string(),
+        };
+        log.lock().unwrap().push(name);
+        Control::Continue
+    };
+    let mut p = Population::new(configuration(vec![Arc::new(observer)])).unwrap();
+    for _ in 0..4 {
+        p = p.evolve();
+        assert!(!p.stop_requested);
+    }
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 8);
+    assert_eq!(events[0], "finished 1");
+    assert_eq!(events[6], "finished 4");
+    assert!(events[7] == "best" || events[7] == format!("stagnation {}", p.stagnation));
+}
+
+#[test]
+fn stop() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let stop_at_two = move |event: &Event| match *event {
+        Event::GenerationFinished(s) if s.generation >= 2 => Control::Stop,
+        _ => {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Control::Continue
+        }
+    };
+    let p = Population::new(configuration(vec![Arc::new(stop_at_two)])).unwrap();
+    let p = p.evolve();
+    assert!(!p.stop_requested);
+    let p = p.evolve();
+    assert!(p.stop_requested);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use genetic::Individual;
+
+/// Function used to calculate the objectives of an Individual,
+/// every objective is maximised
+pub type Objectives<T> = Arc<dyn Fn(&Individual<T>) -> Vec<f64> + Send + Sync>;
+
+/// Verify if the objectives a dominate the objectives b: a is not worse
+/// than b in every objective and it is better in at least one
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| x >= y) && a.iter().zip(b.iter()).any(|(x, y)| x > y)
+}
+
+/// Split the indexes of the objectives in non dominated fronts,
+/// the first front contains the Pareto front
+pub fn non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut domination_count = vec![0usize; n];
+    let mut fronts: Vec<Vec<usize>> = Vec::new();
+    let mut front: Vec<usize> = Vec::new();
+    for p in 0..n {
+        for q in 0..n {
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated[p].push(q);
+            } else if dominates(&objectives[q], &objectives[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            front.push(p);
+        }
+    }
+    while !front.is_empty() {
+        let mut next: Vec<usize> = Vec::new();
+        for &p in &front {
+            for &q in &dominated[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next.push(q);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next;
+    }
+    fronts
+}
+
+/// Get the crowding distance of each index of the front, the Individuals at the
+/// boundaries of the front have an infinite distance
+pub fn crowding_distance(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+    if front.len() < 3 {
+        return vec![f64::INFINITY; front.len()];
+    }
+    let mut distance = vec![0f64; front.len()];
+    let column = |o: usize| -> Vec<f64> { front.iter().map(|i| objectives[*i][o]).collect() };
+    for o in 0..objectives[front[0]].len() {
+        let values = column(o);
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|a, b| values[*a].partial_cmp(&values[*b]).unwrap_or(Ordering::Equal));
+        let min = values[order[0]];
+        let max = values[order[front.len() - 1]];
+        distance[order[0]] = f64::INFINITY;
+        distance[order[front.len() - 1]] = f64::INFINITY;
+        if max > min {
+            for k in 1..front.len() - 1 {
+                distance[order[k]] += (values[order[k + 1]] - values[order[k - 1]]) / (max - min);
+            }
+        }
+    }
+    distance
+}
+
+/// Get the rank (index of the front) and the crowding distance of each objectives
+pub fn rank_and_crowding(objectives: &[Vec<f64>]) -> Vec<(usize, f64)> {
+    let mut v = vec![(0usize, 0f64); objectives.len()];
+    for (rank, front) in non_dominated_sort(objectives).iter().enumerate() {
+        for (i, d) in front.iter().zip(crowding_distance(objectives, front)) {
+            v[*i] = (rank, d);
+        }
+    }
+    v
+}
+
+/// Compare two (rank, crowding distance), the lower rank is better and
+/// with the same rank the higher distance is better
+pub fn crowded_comparison(a: &(usize, f64), b: &(usize, f64)) -> Ordering {
+    b.0.cmp(&a.0).then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+}
+
+/// Get the indexes of the number best objectives, ordered by rank
+/// and crowding distance
+pub fn select_best(objectives: &[Vec<f64>], number: usize) -> Vec<usize> {
+    let mut v: Vec<usize> = Vec::new();
+    for front in non_dominated_sort(objectives) {
+        if v.len() + front.len() <= number {
+            v.extend(front);
+        } else {
+            let distance = crowding_distance(objectives, &front);
+            let mut order: Vec<usize> = (0..front.len()).collect();
+            order.sort_by(|a, b| {
+                distance[*b].partial_cmp(&distance[*a]).unwrap_or(Ordering::Equal)
+            });
+            let missing = number - v.len();
+            v.extend(order.into_iter().take(missing).map(|i| front[i]));
+        }
+        if v.len() >= number {
+            break;
+        }
+    }
+    v
+}
@@ -0,0 +1,41 @@
+#![cfg(test)]
+
+use pareto::*;
+
+#[allow(dead_code)]
+fn objectives() -> Vec<Vec<f64>> {
+    vec![vec![1f64, 5f64],
+         vec![2f64, 4f64],
+         vec![3f64, 3f64],
+         vec![1f64, 1f64],
+         vec![2f64, 2f64],
+         vec![0f64, 0f64]]
+}
+
+#[test]
+fn dominance() {
+    assert!(dominates(&[2f64, 2f64], &[1f64, 2f64]));
+    assert!(!dominates(&[2f64, 2f64], &[2f64, 2f64]));
+    assert!(!dominates(&[3f64, 1f64], &[1f64, 3f64]));
+}
+
+#[test]
+fn fronts() {
+    let fronts = non_dominated_sort(&objectives());
+    assert_eq!(fronts, vec![vec![0, 1, 2], vec![4], vec![3], vec![5]]);
+}
+
+#[test]
+fn crowding() {
+    let d = crowding_distance(&objectives(), &[0, 1, 2]);
+    assert!(d[0].is_infinite() && d[2].is_infinite());
+    assert_eq!(d[1], 2f64);
+}
+
+#[test]
+fn best() {
+    let mut v = select_best(&objectives(), 4);
+    v.sort();
+    assert_eq!(v, vec![0, 1, 2, 4]);
+    assert_eq!(select_best(&objectives(), 2).len(), 2);
+}
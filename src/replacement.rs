@@ -0,0 +1,39 @@
+/// Individual replaced by an offspring in the steady state evolution
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Victim {
+    /// The Individual with the worst score
+    Worst,
+    /// The worst of size Individuals sampled with replacement
+    TournamentLoser(usize),
+}
+
+/// Strategy used to build the next generation from the current one
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Replacement {
+    /// The elites are copied and the rest of the next generation
+    /// is made of offsprings
+    Generational,
+    /// Only offsprings new Individuals are created, each one replaces the
+    /// victim if it is not worse than the victim. The generation of the
+    /// Population counts the evaluations
+    SteadyState {
+        /// Number of offsprings created by each evolve
+        offsprings: usize,
+        /// Individual replaced by each offspring
+        victim: Victim,
+    },
+    /// The (mu + lambda) selection: offsprings new Individuals are created and the
+    /// best population_size Individuals between the current ones and the offsprings
+    /// survive, the current ones survive the ties. The elitism_size is not used
+    MuPlusLambda {
+        /// Number of offsprings created by each evolve, the lambda
+        offsprings: usize,
+    },
+    /// The (mu, lambda) selection: offsprings new Individuals are created and only
+    /// the best population_size of them survive, the offsprings have to be at least
+    /// population_size. The elitism_size is not used
+    MuCommaLambda {
+        /// Number of offsprings created by each evolve, the lambda
+        offsprings: usize,
+    },
+}
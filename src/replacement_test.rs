@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use genetic::*;
+use replacement::*;
+use test_helpers::configuration_builder;
+use error::{ConfigurationError, Error};
+
+#[allow(dead_code)]
+fn configuration(replacement: Replacement, calls: Arc<AtomicUsize>) -> PopulationConfiguration<u8> {
+    let fitness = move |i: &Individual<u8>| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        i.genes.iter().map(|g| (*g / 16) as i32).sum()
+    };
+    configuration_builder(fitness, 32, 8, 4)
+        .mutation_rate(0.1f32)
+        .replacement(replacement)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn steady_state_counts_evaluations() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let replacement = Replacement::SteadyState {
+        offsprings: 3,
+        victim: Victim::Worst,
+    };
+    let mut p = Population::new(configuration(replacement, calls.clone())).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 32);
+    for _ in 0..10 {
+        p = p.evolve();
+    }
+    assert_eq!(p.generation, 30);
+    assert_eq!(calls.load(Ordering::SeqCst), 32 + 30);
+    assert_eq!(p.individuals_and_scores.len(), 32);
+}
+
+#[test]
+fn steady_state_worst_keeps_best() {
+    let replacement = Replacement::SteadyState {
+        offsprings: 2,
+        victim: Victim::Worst,
+    };
+    let mut p = Population::new(configuration(replacement, Arc::new(AtomicUsize::new(0)))).unwrap();
+    let mut best = p.get_fittest().unwrap().1;
+    let mut worst = p.individuals_and_scores.iter().map(|a| a.1).min().unwrap();
+    for _ in 0..20 {
+        p = p.evolve();
+        assert!(p.get_fittest().unwrap().1 >= best);
+        let new_worst = p.individuals_and_scores.iter().map(|a| a.1).min().unwrap();
+        assert!(new_worst >= worst);
+        best = p.get_fittest().unwrap().1;
+        worst = new_worst;
+    }
+}
+
+#[test]
+fn steady_state_tournament_loser() {
+    let replacement = Replacement::SteadyState {
+        offsprings: 4,
+        victim: Victim::TournamentLoser(3),
+    };
+    let p = Population::new(configuration(replacement, Arc::new(AtomicUsize::new(0)))).unwrap();
+    let pe = p.evolve().evolve();
+    assert_eq!(pe.generation, 8);
+    let differences = p.individuals_and_scores
+        .iter()
+        .zip(pe.individuals_and_scores.iter())
+        .filter(|&(a, b)| a.0 != b.0)
+        .count();
+    assert!(differences <= 8);
+}
+
+#[test]
+fn steady_state_without_offsprings() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut c = configuration(Replacement::Generational, calls);
+    c.replacement = Replacement::SteadyState {
+        offsprings: 0,
+        victim: Victim::Worst,
+    };
+    match c.validate() {
+        Err(Error::InvalidConfiguration(problems)) => {
+            assert_eq!(problems,
+                       vec![ConfigurationError::TooFewOffsprings {
+                                offsprings: 0,
+                                population_size: 32,
+                            }])
+        }
+        _ => panic!("the steady state creates no offsprings"),
+    }
+}
+
+#[test]
+fn mu_plus_lambda() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let replacement = Replacement::MuPlusLambda { offsprings: 8 };
+    let mut p = Population::new(configuration(replacement, calls.clone())).unwrap();
+    let mut scores: Vec<i32> = p.individuals_and_scores.iter().map(|a| a.1).collect();
+    for _ in 0..10 {
+        p = p.evolve();
+        let mut new_scores: Vec<i32> = p.individuals_and_scores.iter().map(|a| a.1).collect();
+        scores.sort_by(|a, b| b.cmp(a));
+        new_scores.sort_by(|a, b| b.cmp(a));
+        assert!(new_scores.iter().zip(scores.iter()).all(|(n, o)| n >= o));
+        scores = new_scores;
+    }
+    assert_eq!(p.generation, 10);
+    assert_eq!(p.individuals_and_scores.len(), 32);
+    assert_eq!(calls.load(Ordering::SeqCst), 32 + 10 * 8);
+}
+
+#[test]
+fn mu_comma_lambda() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let replacement = Replacement::MuCommaLambda { offsprings: 48 };
+    let p = Population::new(configuration(replacement, calls.clone())).unwrap();
+    let pe = p.evolve();
+    assert_eq!(pe.generation, 1);
+    assert_eq!(pe.individuals_and_scores.len(), 32);
+    assert_eq!(calls.load(Ordering::SeqCst), 32 + 48);
+    assert_eq!(pe.evaluations, 32 + 48);
+}
+
+#[test]
+fn mu_comma_lambda_too_few_offsprings() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut c = configuration(Replacement::Generational, calls);
+    c.replacement = Replacement::MuCommaLambda { offsprings: 16 };
+    assert!(c.validate().is_err());
+}
+
+#[test]
+fn mu_plus_lambda_without_offsprings() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut c = configuration(Replacement::Generational, calls);
+    c.replacement = Replacement::MuPlusLambda { offsprings: 0 };
+    match c.validate() {
+        Err(Error::InvalidConfiguration(problems)) => {
+            assert_eq!(problems,
+                       vec![ConfigurationError::TooFewOffsprings {
+                                offsprings: 0,
+                                population_size: 32,
+                            }])
+        }
+        _ => panic!("the (mu + lambda) creates no offsprings"),
+    }
+}
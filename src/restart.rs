@@ -0,0 +1,53 @@
+use error::{ConfigurationError, check_rate};
+
+/// Strategy used to inject new genetic material when the best score of the
+/// Population stops improving. The new Individuals are created by the generator
+/// of the configuration, which can seed them with an heuristic
+#[derive(Debug,Clone,Copy,PartialEq)]
+pub enum Restart {
+    /// After patience generations without a better Individual, each generation
+    /// the worst rate * population_size Individuals are replaced by new ones.
+    /// With patience 0 the immigrants arrive at every generation
+    RandomImmigrants {
+        /// Fraction of the Population replaced, between 0 and 1
+        rate: f32,
+        /// Number of generations without improvements before the immigrants arrive
+        patience: usize,
+    },
+    /// After patience generations without a better Individual, only the elites
+    /// survive and the rest of the Population is replaced by new Individuals.
+    /// The count of the generations without improvements starts again from 0
+    Catastrophe {
+        /// Number of generations without improvements before the restart, at least 1
+        patience: usize,
+    },
+}
+
+impl Restart {
+    /// Get the number of Individuals replaced after stagnation generations
+    /// without improvements, the elites are never replaced by a catastrophe
+    pub fn replaced(&self,
+                    stagnation: usize,
+                    population_size: usize,
+                    elitism_size: usize)
+                    -> usize {
+        match *self {
+            Restart::RandomImmigrants { rate, patience } if stagnation >= patience => {
+                ((population_size as f32 * rate).round() as usize).min(population_size)
+            }
+            Restart::Catastrophe { patience } if stagnation >= patience.max(1) => {
+                population_size.saturating_sub(elitism_size)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Get the problem of the parameters of the Restart, if any
+    pub fn check(&self) -> Option<ConfigurationError> {
+        match *self {
+            Restart::RandomImmigrants { rate, .. } => check_rate("immigrants rate", rate),
+            Restart::Catastrophe { patience: 0 } => Some(ConfigurationError::NoPatience),
+            Restart::Catastrophe { .. } => None,
+        }
+    }
+}
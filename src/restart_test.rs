@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use std::sync::{Arc, Mutex};
+
+use genetic::*;
+use restart::*;
+use observer::{Control, Event};
+use test_helpers::configuration_builder;
+use error::{ConfigurationError, Error};
+use genetic_planner::PlannerConfiguration;
+
+#[allow(dead_code)]
+fn configuration(restart: Restart) -> PopulationConfiguration<u8> {
+    configuration_builder(|_: &Individual<u8>| 0, 32, 4, 12)
+        .elitism_size(2)
+        .restart(restart)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn replaced_individuals() {
+    let immigrants = Restart::RandomImmigrants {
+        rate: 0.25f32,
+        patience: 2,
+    };
+    assert_eq!(immigrants.replaced(1, 32, 2), 0);
+    assert_eq!(immigrants.replaced(2, 32, 2), 8);
+    let catastrophe = Restart::Catastrophe { patience: 0 };
+    assert_eq!(catastrophe.replaced(0, 32, 2), 0);
+    assert_eq!(catastrophe.replaced(1, 32, 2), 30);
+    assert_eq!(catastrophe.replaced(1, 32, 40), 0);
+}
+
+#[test]
+fn random_immigrants() {
+    let restart = Restart::RandomImmigrants {
+        rate: 0.25f32,
+        patience: 2,
+    };
+    let mut p = Population::new(configuration(restart)).unwrap();
+    let mut evaluations = vec![p.evaluations];
+    for _ in 0..3 {
+        p = p.evolve();
+        evaluations.push(p.evaluations);
+        assert_eq!(p.individuals_and_scores.len(), 32);
+    }
+    assert_eq!(evaluations, vec![32, 62, 100, 138]);
+    assert_eq!(p.stagnation, 3);
+}
+
+#[test]
+fn immigrants_in_offsprings_batch() {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let shared_batches = batches.clone();
+    let mut c = configuration(Restart::RandomImmigrants {
+        rate: 0.25f32,
+        patience: 2,
+    });
+    c.batch_fitness = Some(Arc::new(move |individuals: &[Individual<u8>]| {
+        shared_batches.lock().unwrap().push(individuals.len());
+        vec![0; individuals.len()]
+    }));
+    let mut p = Population::new(c).unwrap();
+    for _ in 0..3 {
+        p = p.evolve();
+    }
+    assert_eq!(*batches.lock().unwrap(), vec![32, 30, 38, 38]);
+    assert_eq!(p.evaluations, 138);
+}
+
+#[test]
+fn catastrophe() {
+    let restarts = Arc::new(Mutex::new(Vec::new()));
+    let shared_restarts = restarts.clone();
+    let mut c = configuration(Restart::Catastrophe { patience: 3 });
+    c.observers.push(Arc::new(move |e: &Event| {
+        if let Event::Restarted(s) = *e {
+            shared_restarts.lock().unwrap().push(s.generation);
+        }
+        Control::Continue
+    }));
+    let mut p = Population::new(c).unwrap();
+    for _ in 0..7 {
+        p = p.evolve();
+        assert_eq!(p.individuals_and_scores.len(), 32);
+    }
+    assert_eq!(*restarts.lock().unwrap(), vec![3, 6]);
+    assert_eq!(p.stagnation, 1);
+    assert_eq!(p.evaluations, 32 + 7 * 30 + 2 * 30);
+}
+
+#[test]
+fn distinct_survivors() {
+    let mut c = configuration(Restart::Catastrophe { patience: 1 });
+    c.fitness = Arc::new(|i: &Individual<u8>| i.genes.iter().map(|g| *g as i32).sum());
+    c.mutation_rate = 0f32;
+    let mut v = vec![(Individual::new_with_vec(vec![255u8; 4]), 1020); 31];
+    v.push((Individual::new_with_vec(vec![0u8; 4]), 0));
+    let p = Population::new_with_vec(v, c, 0).unwrap().evolve();
+    assert_eq!(p.stagnation, 0);
+    assert_eq!(p.individuals_and_scores[0].0.genes, vec![255u8; 4]);
+    assert!(p.individuals_and_scores[1].0 != p.individuals_and_scores[0].0);
+}
+
+#[test]
+fn seeded_restarts() {
+    let restart = Restart::RandomImmigrants {
+        rate: 0.5f32,
+        patience: 0,
+    };
+    let genomes = |threadpool_size| {
+        let mut c = configuration(restart);
+        c.threadpool_size = threadpool_size;
+        let mut p = Population::new(c).unwrap();
+        for _ in 0..3 {
+            p = p.evolve();
+        }
+        p.individuals_and_scores.into_iter().map(|a| a.0).collect::<Vec<_>>()
+    };
+    assert!(genomes(1) == genomes(4));
+}
+
+#[test]
+fn invalid_rate() {
+    let restart = Restart::RandomImmigrants {
+        rate: 1.5f32,
+        patience: 0,
+    };
+    let result = PopulationConfiguration::builder(|_: &Individual<u8>| 0).restart(restart).build();
+    assert!(result.is_err());
+}
+
+#[test]
+fn invalid_patience() {
+    let restart = Restart::Catastrophe { patience: 0 };
+    assert_eq!(restart.check(), Some(ConfigurationError::NoPatience));
+    let result = PopulationConfiguration::builder(|_: &Individual<u8>| 0).restart(restart).build();
+    match result {
+        Err(Error::InvalidConfiguration(problems)) => {
+            assert_eq!(problems, vec![ConfigurationError::NoPatience])
+        }
+        _ => panic!("the catastrophe has no patience"),
+    }
+    assert!(PlannerConfiguration::builder().restart(restart).build().is_err());
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::num::ParseFloatError;
+use std::str::FromStr;
+
+/// Score of an Individual, any totally ordered type
+pub trait Score: Clone + Ord + Send + Sync + Debug + 'static {
+    /// Get the score as f64, used by the selections which
+    /// need the magnitude of the score and by the statistics
+    fn to_f64(&self) -> f64;
+
+    /// Verify if the score is not a number, which is
+    /// the worst score in both the directions
+    fn is_nan(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! primitive_score {
+    ($($t:ty),*) => {
+        $(
+            impl Score for $t {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    }
+}
+
+primitive_score!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Wrapper of f64 with a total order, used as Score by the fitness
+/// functions which return floating point numbers. All the NaN are equal
+/// and greater than every other number, but the Direction always
+/// considers NaN the worst score
+#[derive(Debug,Clone,Copy,Default)]
+pub struct OrdF64(pub f64);
+
+impl PartialEq for OrdF64 {
+    fn eq(&self, other: &OrdF64) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdF64 {}
+
+impl PartialOrd for OrdF64 {
+    fn partial_cmp(&self, other: &OrdF64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdF64 {
+    fn cmp(&self, other: &OrdF64) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.0.total_cmp(&other.0),
+        }
+    }
+}
+
+impl Display for OrdF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for OrdF64 {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<OrdF64, ParseFloatError> {
+        s.parse().map(OrdF64)
+    }
+}
+
+impl Score for OrdF64 {
+    fn to_f64(&self) -> f64 {
+        self.0
+    }
+
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+}
+
+/// Direction of the optimisation of the score
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum Direction {
+    /// The highest score is the best
+    Maximise,
+    /// The lowest score is the best
+    Minimise,
+}
+
+impl Direction {
+    /// Compare two scores, Ordering::Greater means that a is better than b.
+    /// NaN is worse than every other score
+    pub fn compare<S: Score>(&self, a: &S, b: &S) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => match *self {
+                Direction::Maximise => a.cmp(b),
+                Direction::Minimise => b.cmp(a),
+            },
+        }
+    }
+
+    /// Verify if the score a is better than the score b
+    pub fn is_better<S: Score>(&self, a: &S, b: &S) -> bool {
+        self.compare(a, b) == Ordering::Greater
+    }
+
+    /// Get the value of the score for the selections, where
+    /// an higher value is always better, NaN is -infinity
+    pub fn selection_value<S: Score>(&self, score: &S) -> f64 {
+        if score.is_nan() {
+            return f64::NEG_INFINITY;
+        }
+        match *self {
+            Direction::Maximise => score.to_f64(),
+            Direction::Minimise => -score.to_f64(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(test)]
+
+use std::cmp::Ordering;
+
+use score::*;
+
+#[test]
+fn ord_f64() {
+    assert!(OrdF64(1.5f64) > OrdF64(-2f64));
+    assert_eq!(OrdF64(0.25f64), OrdF64(0.25f64));
+    assert!(OrdF64(f64::NAN) > OrdF64(f64::INFINITY));
+    assert!(OrdF64(-f64::NAN) > OrdF64(f64::INFINITY));
+    assert_eq!(OrdF64(-f64::NAN), OrdF64(f64::NAN));
+    assert_eq!(OrdF64(3f64).to_f64(), 3f64);
+}
+
+#[test]
+fn direction() {
+    assert_eq!(Direction::Maximise.compare(&2, &1), Ordering::Greater);
+    assert_eq!(Direction::Minimise.compare(&2, &1), Ordering::Less);
+    assert!(Direction::Minimise.is_better(&-3, &1));
+    assert!(!Direction::Maximise.is_better(&1, &1));
+    assert_eq!(Direction::Maximise.selection_value(&4u8), 4f64);
+    assert_eq!(Direction::Minimise.selection_value(&4u8), -4f64);
+    for direction in [Direction::Maximise, Direction::Minimise] {
+        let nan = OrdF64(-f64::NAN);
+        assert!(direction.is_better(&OrdF64(f64::INFINITY), &nan));
+        assert!(direction.is_better(&OrdF64(f64::NEG_INFINITY), &nan));
+        assert_eq!(direction.selection_value(&nan), f64::NEG_INFINITY);
+    }
+}
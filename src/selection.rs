@@ -0,0 +1,269 @@
+extern crate rand;
+use rand::{Rng, XorShiftRng};
+
+use std::cmp::Ordering;
+
+use error::{ConfigurationError, check_rate};
+
+/// Strategy used to choose the parents of the offsprings
+pub trait Selection: Send + Sync {
+    /// Return the indexes of number parents chosen from scores,
+    /// an higher score is a better score. If scores is empty
+    /// no parents are returned
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize>;
+
+    /// Get the problem of the parameters of the Selection, if any
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        Ok(())
+    }
+}
+
+/// Get the problem of the size of a tournament, if it is 0
+fn check_tournament(size: usize) -> Result<(), ConfigurationError> {
+    if size == 0 {
+        Err(ConfigurationError::EmptyTournament)
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the index of the best score between the indexes
+fn best_of(scores: &[f64], indexes: &[usize]) -> usize {
+    let mut best = indexes[0];
+    for &i in indexes.iter().skip(1) {
+        if scores[i] > scores[best] {
+            best = i;
+        }
+    }
+    best
+}
+
+/// Get the indexes of the scores, from the worst to the best
+fn ranking(scores: &[f64]) -> Vec<usize> {
+    let mut indexes: Vec<usize> = (0..scores.len()).collect();
+    indexes.sort_by(|a, b| scores[*a].partial_cmp(&scores[*b]).unwrap_or(Ordering::Equal));
+    indexes
+}
+
+/// Get the weights used by the fitness proportional selections, the finite
+/// scores are shifted so that the worst one has weight 0. NaN and -infinity
+/// have weight 0, infinity has the weight of the best finite score. If the
+/// weights are all 0 or their sum is not finite, they are all 1
+fn proportional_weights(scores: &[f64]) -> Vec<f64> {
+    let finite = scores.iter().cloned().filter(|s| s.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = scores.iter()
+        .map(|s| if s.is_finite() {
+            s - min
+        } else if *s == f64::INFINITY && max.is_finite() {
+            max - min
+        } else {
+            0f64
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    if total <= 0f64 || !total.is_finite() {
+        vec![1f64; scores.len()]
+    } else {
+        weights
+    }
+}
+
+/// Get the index of the weight which contains the point
+fn spin(weights: &[f64], point: f64) -> usize {
+    let mut acc = 0f64;
+    for (i, w) in weights.iter().enumerate() {
+        acc += *w;
+        if point < acc {
+            return i;
+        }
+    }
+    weights.len() - 1
+}
+
+/// Select the best Individual of size Individuals sampled with replacement
+#[derive(Debug,Clone)]
+pub struct Tournament {
+    /// Number of Individuals of each tournament
+    pub size: usize,
+}
+
+impl Tournament {
+    /// Create a new Tournament of size Individuals
+    pub fn new(size: usize) -> Tournament {
+        Tournament { size }
+    }
+}
+
+impl Selection for Tournament {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+        let size = self.size.max(1);
+        (0..number)
+            .map(|_| {
+                let v: Vec<usize> = (0..size).map(|_| rng.gen_range(0, scores.len())).collect();
+                best_of(scores, &v)
+            })
+            .collect()
+    }
+
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        check_tournament(self.size)
+    }
+}
+
+/// Select the best Individual of size distinct Individuals
+#[derive(Debug,Clone)]
+pub struct TournamentWithoutReplacement {
+    /// Number of Individuals of each tournament
+    pub size: usize,
+}
+
+impl TournamentWithoutReplacement {
+    /// Create a new TournamentWithoutReplacement of size Individuals
+    pub fn new(size: usize) -> TournamentWithoutReplacement {
+        TournamentWithoutReplacement { size }
+    }
+}
+
+impl Selection for TournamentWithoutReplacement {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+        let size = self.size.max(1).min(scores.len());
+        let mut indexes: Vec<usize> = (0..scores.len()).collect();
+        (0..number)
+            .map(|_| {
+                for i in 0..size {
+                    let j = rng.gen_range(i, indexes.len());
+                    indexes.swap(i, j);
+                }
+                best_of(scores, &indexes[..size])
+            })
+            .collect()
+    }
+
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        check_tournament(self.size)
+    }
+}
+
+/// Select the Individuals with a probability proportional to the score
+#[derive(Debug,Clone)]
+pub struct RouletteWheel;
+
+impl Selection for RouletteWheel {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+        let weights = proportional_weights(scores);
+        let total: f64 = weights.iter().sum();
+        (0..number).map(|_| spin(&weights, rng.gen_range(0f64, total))).collect()
+    }
+}
+
+/// Select the Individuals with a probability proportional to the score,
+/// using number equally spaced pointers on the same wheel
+#[derive(Debug,Clone)]
+pub struct StochasticUniversalSampling;
+
+impl Selection for StochasticUniversalSampling {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() || number == 0 {
+            return Vec::new();
+        }
+        let weights = proportional_weights(scores);
+        let total: f64 = weights.iter().sum();
+        let distance = total / number as f64;
+        let start = rng.gen_range(0f64, distance);
+        let mut v: Vec<usize> = (0..number)
+            .map(|i| spin(&weights, start + distance * i as f64))
+            .collect();
+        rng.shuffle(&mut v);
+        v
+    }
+}
+
+/// Select the Individuals with a probability that depends linearly
+/// on their rank in the Population
+#[derive(Debug,Clone)]
+pub struct RankBased {
+    /// Expected number of copies of the best Individual, between 1 and 2
+    pub selection_pressure: f64,
+}
+
+impl RankBased {
+    /// Create a new RankBased with the selection_pressure
+    pub fn new(selection_pressure: f64) -> RankBased {
+        RankBased { selection_pressure }
+    }
+}
+
+impl Selection for RankBased {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+        let ranking = ranking(scores);
+        let n = scores.len() as f64;
+        let sp = self.selection_pressure;
+        let weights: Vec<f64> = (0..scores.len())
+            .map(|r| {
+                if n > 1f64 {
+                    (2f64 - sp) / n + 2f64 * r as f64 * (sp - 1f64) / (n * (n - 1f64))
+                } else {
+                    1f64
+                }
+            })
+            .collect();
+        let total: f64 = weights.iter().sum();
+        (0..number)
+            .map(|_| ranking[spin(&weights, rng.gen_range(0f64, total))])
+            .collect()
+    }
+
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        if (1f64..=2f64).contains(&self.selection_pressure) {
+            Ok(())
+        } else {
+            Err(ConfigurationError::SelectionPressureOutOfRange(self.selection_pressure))
+        }
+    }
+}
+
+/// Select uniformly the Individuals from the best proportion of the Population
+#[derive(Debug,Clone)]
+pub struct Truncation {
+    /// Proportion of the Population that can be selected, between 0 and 1
+    pub proportion: f64,
+}
+
+impl Truncation {
+    /// Create a new Truncation of the best proportion of the Population
+    pub fn new(proportion: f64) -> Truncation {
+        Truncation { proportion }
+    }
+}
+
+impl Selection for Truncation {
+    fn select(&self, scores: &[f64], number: usize, rng: &mut XorShiftRng) -> Vec<usize> {
+        if scores.is_empty() {
+            return Vec::new();
+        }
+        let ranking = ranking(scores);
+        let size = ((scores.len() as f64 * self.proportion).ceil() as usize)
+            .max(1)
+            .min(scores.len());
+        let best = &ranking[scores.len() - size..];
+        (0..number).map(|_| best[rng.gen_range(0, size)]).collect()
+    }
+
+    fn validate(&self) -> Result<(), ConfigurationError> {
+        check_rate("truncation proportion", self.proportion as f32).map_or(Ok(()), Err)
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+extern crate rand;
+use rand::{SeedableRng, XorShiftRng};
+
+use selection::*;
+
+#[allow(dead_code)]
+fn scores() -> Vec<f64> {
+    vec![0f64, 1f64, 2f64, 3f64, 4f64, 5f64, 6f64, 7f64]
+}
+
+#[allow(dead_code)]
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn select_number() {
+    let selections: Vec<Box<dyn Selection>> = vec![Box::new(Tournament::new(3)),
+                                                   Box::new(TournamentWithoutReplacement::new(3)),
+                                                   Box::new(RouletteWheel),
+                                                   Box::new(StochasticUniversalSampling),
+                                                   Box::new(RankBased::new(1.5)),
+                                                   Box::new(Truncation::new(0.5))];
+    for s in selections {
+        let v = s.select(&scores(), 20, &mut rng());
+        assert_eq!(v.len(), 20);
+        assert!(v.iter().all(|i| *i < scores().len()));
+    }
+}
+
+#[test]
+fn tournament_without_replacement() {
+    let v = TournamentWithoutReplacement::new(8).select(&scores(), 10, &mut rng());
+    assert!(v.iter().all(|i| *i == 7));
+}
+
+#[test]
+fn truncation() {
+    let v = Truncation::new(0.25).select(&scores(), 50, &mut rng());
+    assert!(v.iter().all(|i| *i >= 6));
+}
+
+#[test]
+fn roulette_wheel() {
+    let v = RouletteWheel.select(&scores(), 200, &mut rng());
+    assert!(v.iter().all(|i| *i != 0));
+    let equals = vec![3f64; 4];
+    let v2 = RouletteWheel.select(&equals, 10, &mut rng());
+    assert_eq!(v2.len(), 10);
+}
+
+#[test]
+fn stochastic_universal_sampling() {
+    let mut v = StochasticUniversalSampling.select(&scores(), 28, &mut rng());
+    v.sort();
+    for i in 1..8 {
+        assert_eq!(v.iter().filter(|a| **a == i).count(), i);
+    }
+}
+
+#[test]
+fn non_finite_scores() {
+    let scores = vec![f64::NAN, f64::NEG_INFINITY, 1f64, 2f64, f64::INFINITY];
+    for v in [RouletteWheel.select(&scores, 100, &mut rng()),
+              StochasticUniversalSampling.select(&scores, 100, &mut rng())] {
+        assert_eq!(v.len(), 100);
+        assert!(v.iter().all(|i| *i == 3 || *i == 4));
+    }
+    let v = RouletteWheel.select(&[f64::NAN, f64::INFINITY], 10, &mut rng());
+    assert_eq!(v.len(), 10);
+}
+
+#[test]
+fn select_empty() {
+    let selections: Vec<Box<dyn Selection>> = vec![Box::new(Tournament::new(3)),
+                                                   Box::new(TournamentWithoutReplacement::new(3)),
+                                                   Box::new(RouletteWheel),
+                                                   Box::new(StochasticUniversalSampling),
+                                                   Box::new(RankBased::new(1.5)),
+                                                   Box::new(Truncation::new(0.5))];
+    for s in selections {
+        assert!(s.select(&[], 4, &mut rng()).is_empty());
+    }
+}
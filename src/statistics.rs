@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::time::Duration;
+
+use genetic::Individual;
+use niching::hamming;
+use score::{Direction, Score};
+
+/// Description of a generation of a Population
+#[derive(Debug,Clone,PartialEq)]
+pub struct Statistics {
+    /// Generation of the Population
+    pub generation: usize,
+    /// Best score, accordingly the direction
+    pub best: f64,
+    /// Worst score, accordingly the direction
+    pub worst: f64,
+    /// Mean of the scores
+    pub mean: f64,
+    /// Median of the scores
+    pub median: f64,
+    /// Standard deviation of the scores
+    pub standard_deviation: f64,
+    /// Mean Hamming distance between two genomes, divided by the length of
+    /// the longest genome: 0 when all the genomes are equal. None unless the
+    /// statistics of the genomes are requested, see with_genomes
+    pub diversity: Option<f64>,
+    /// Number of different genomes, None unless the statistics
+    /// of the genomes are requested, see with_genomes
+    pub unique_genomes: Option<usize>,
+    /// Number of Individuals evaluated since the creation of the Population
+    pub evaluations: usize,
+    /// Wall time used to create the generation
+    pub elapsed: Duration,
+    /// Mutation rate used to create the next generation
+    pub mutation_rate: f32,
+    /// Crossover rate used to create the next generation
+    pub uniform_rate: f32,
+}
+
+/// Max number of Individuals used to estimate the diversity of a Population
+pub const DIVERSITY_SAMPLE: usize = 100;
+
+/// Get at most DIVERSITY_SAMPLE genomes of the individuals, evenly spaced
+fn sample<T, S>(individuals: &[(Individual<T>, S)]) -> Vec<&Individual<T>> {
+    let step = individuals.len().div_ceil(DIVERSITY_SAMPLE).max(1);
+    individuals.iter().step_by(step).map(|a| &a.0).collect()
+}
+
+/// Get the number of different genomes of the individuals
+pub fn unique_genomes<T: Hash + Eq, S>(individuals: &[(Individual<T>, S)]) -> usize {
+    individuals.iter().map(|a| &a.0.genes).collect::<HashSet<_>>().len()
+}
+
+/// Get the fraction of different genomes of the individuals, estimated
+/// on at most DIVERSITY_SAMPLE of them
+pub fn unique_fraction<T: PartialEq, S>(individuals: &[(Individual<T>, S)]) -> f64 {
+    let sample = sample(individuals);
+    if sample.is_empty() {
+        return 0f64;
+    }
+    let mut unique: Vec<&Individual<T>> = Vec::new();
+    for i in &sample {
+        if !unique.contains(i) {
+            unique.push(i);
+        }
+    }
+    unique.len() as f64 / sample.len() as f64
+}
+
+/// Get the mean Hamming distance between the genomes of the individuals, divided
+/// by the length of the longest genome, estimated on at most DIVERSITY_SAMPLE of them
+pub fn diversity<T: PartialEq, S>(individuals: &[(Individual<T>, S)]) -> f64 {
+    let sample = sample(individuals);
+    let longest = sample.iter().map(|i| i.genes.len()).max().unwrap_or(0);
+    if sample.len() < 2 || longest == 0 {
+        return 0f64;
+    }
+    let mut total = 0f64;
+    for (n, a) in sample.iter().enumerate() {
+        for b in &sample[n + 1..] {
+            total += hamming(a, b);
+        }
+    }
+    let pairs = (sample.len() * (sample.len() - 1) / 2) as f64;
+    total / pairs / longest as f64
+}
+
+impl Statistics {
+    /// Create the Statistics of the scores of the individuals, the generation,
+    /// the evaluations, the elapsed time and the rates are 0. The statistics
+    /// of the genomes are not calculated. The best and the worst scores follow
+    /// the direction, where NaN is the worst score, the other statistics
+    /// ignore the NaN scores
+    pub fn new<T, S>(individuals: &[(Individual<T>, S)], direction: Direction) -> Statistics
+        where S: Score
+    {
+        let mut scores: Vec<f64> = individuals.iter()
+            .map(|a| a.1.to_f64())
+            .filter(|s| !s.is_nan())
+            .collect();
+        scores.sort_by(|a, b| a.total_cmp(b));
+        let n = scores.len();
+        let (mean, median, standard_deviation) = if n == 0 {
+            (0f64, 0f64, 0f64)
+        } else {
+            let mean = scores.iter().sum::<f64>() / n as f64;
+            let median = if n.is_multiple_of(2) {
+                (scores[n / 2 - 1] + scores[n / 2]) / 2f64
+            } else {
+                scores[n / 2]
+            };
+            let variance = scores.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / n as f64;
+            (mean, median, variance.sqrt())
+        };
+        let all = || individuals.iter().map(|a| &a.1);
+        let best = all().max_by(|a, b| direction.compare(*a, *b)).map_or(0f64, |s| s.to_f64());
+        let worst = all().min_by(|a, b| direction.compare(*a, *b)).map_or(0f64, |s| s.to_f64());
+        Statistics {
+            generation: 0,
+            best,
+            worst,
+            mean,
+            median,
+            standard_deviation,
+            diversity: None,
+            unique_genomes: None,
+            evaluations: 0,
+            elapsed: Duration::new(0, 0),
+            mutation_rate: 0f32,
+            uniform_rate: 0f32,
+        }
+    }
+
+    /// Return the Statistics with the statistics of the genomes of the individuals,
+    /// which are slower to calculate than the ones of the scores
+    pub fn with_genomes<T, S>(mut self, individuals: &[(Individual<T>, S)]) -> Statistics
+        where T: Hash + Eq
+    {
+        self.diversity = Some(diversity(individuals));
+        self.unique_genomes = Some(unique_genomes(individuals));
+        self
+    }
+}
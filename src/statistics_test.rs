@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use genetic::*;
+use statistics::*;
+use test_helpers::configuration_builder;
+use score::{Direction, OrdF64};
+
+#[allow(dead_code)]
+fn individuals() -> Vec<(Individual<u8>, i32)> {
+    vec![(Individual::new_with_vec(vec![1u8, 1, 1, 1]), 4),
+         (Individual::new_with_vec(vec![1u8, 1, 1, 1]), 2),
+         (Individual::new_with_vec(vec![0u8, 0, 1, 1]), 1),
+         (Individual::new_with_vec(vec![0u8, 0, 0, 0]), 1)]
+}
+
+#[test]
+fn scores() {
+    let s = Statistics::new(&individuals(), Direction::Maximise);
+    assert_eq!(s.best, 4f64);
+    assert_eq!(s.worst, 1f64);
+    assert_eq!(s.mean, 2f64);
+    assert_eq!(s.median, 1.5f64);
+    assert!((s.standard_deviation - 1.5f64.sqrt()).abs() < 1e-9);
+    let s = Statistics::new(&individuals(), Direction::Minimise);
+    assert_eq!(s.best, 1f64);
+    assert_eq!(s.worst, 4f64);
+}
+
+#[test]
+fn nan_scores() {
+    let scored = |score| (Individual::new_with_vec(vec![0u8]), OrdF64(score));
+    let v = vec![scored(f64::NAN), scored(123f64), scored(-f64::NAN), scored(1f64)];
+    for direction in [Direction::Maximise, Direction::Minimise] {
+        let s = Statistics::new(&v, direction);
+        assert!(s.worst.is_nan());
+        assert_eq!(s.mean, 62f64);
+        assert_eq!(s.median, 62f64);
+    }
+    assert_eq!(Statistics::new(&v, Direction::Maximise).best, 123f64);
+    assert_eq!(Statistics::new(&v, Direction::Minimise).best, 1f64);
+}
+
+#[test]
+fn genomes() {
+    assert_eq!(unique_genomes(&individuals()), 3);
+    // distances 0, 2, 4, 2, 4, 2 over 6 pairs of 4 genes
+    assert_eq!(diversity(&individuals()), 14f64 / 6f64 / 4f64);
+    assert_eq!(unique_fraction(&individuals()), 0.75f64);
+    let s = Statistics::new(&individuals(), Direction::Maximise);
+    assert_eq!(s.unique_genomes, None);
+    assert_eq!(s.diversity, None);
+    let s = s.with_genomes(&individuals());
+    assert_eq!(s.unique_genomes, Some(3));
+    assert_eq!(s.diversity, Some(14f64 / 6f64 / 4f64));
+    let empty: Vec<(Individual<u8>, i32)> = Vec::new();
+    let s = Statistics::new(&empty, Direction::Maximise).with_genomes(&empty);
+    assert_eq!(s.diversity, Some(0f64));
+}
+
+#[test]
+fn sampled_diversity() {
+    // 1000 genomes cycling through three values: the sample keeps them all
+    let many: Vec<(Individual<u8>, i32)> = (0..1000)
+        .map(|n| (Individual::new_with_vec(vec![(n % 3) as u8; 4]), 0))
+        .collect();
+    assert_eq!(unique_genomes(&many), 3);
+    assert!(diversity(&many) > 0f64 && diversity(&many) <= 1f64);
+    assert!(unique_fraction(&many) <= 3f64 / DIVERSITY_SAMPLE as f64);
+}
+
+#[test]
+fn population_statistics() {
+    let c = configuration_builder(|i: &Individual<u8>| i.genes[0] as i32, 16, 4, 1)
+        .mutation_rate(0.3f32)
+        .elitism_size(2)
+        .build()
+        .unwrap();
+    let mut p = Population::new(c).unwrap();
+    let s = p.get_statistics();
+    assert_eq!(s.generation, 0);
+    assert_eq!(s.evaluations, 16);
+    assert_eq!(s.mutation_rate, 0.3f32);
+    p = p.evolve().evolve();
+    let s = p.get_statistics();
+    assert_eq!(s.generation, 2);
+    assert_eq!(s.evaluations, 16 + 14 + 14);
+    assert_eq!(s.best, p.get_fittest().unwrap().1 as f64);
+    assert!(s.worst <= s.median && s.median <= s.best);
+}
@@ -0,0 +1,201 @@
+use std::time::{Duration, Instant};
+
+use error::{ConfigurationError, Error, check};
+use genetic::Evolution;
+use observer::Event;
+use score::{Direction, OrdF64};
+use statistics::Statistics;
+
+/// Criterion used to end a run, all the counters start at the beginning of the run
+#[derive(Debug,Clone,PartialEq)]
+pub enum Termination {
+    /// The run has created the number of generations
+    MaxGenerations(usize),
+    /// The run has evaluated the number of Individuals
+    MaxEvaluations(usize),
+    /// The run has lasted the duration
+    WallClock(Duration),
+    /// The best score has reached the target, accordingly the direction
+    TargetFitness(f64),
+    /// The best score has not improved for the number of generations
+    NoImprovement(usize),
+    /// The goal is reached, for the planner the best Plan reaches a goal state
+    GoalReached,
+    /// An Observer has asked to stop, it always ends the run
+    StopRequested,
+    /// All the criteria are satisfied
+    All(Vec<Termination>),
+    /// At least one of the criteria is satisfied
+    Any(Vec<Termination>),
+}
+
+/// State of a run checked by the Termination criteria
+#[derive(Debug,Clone,PartialEq)]
+pub struct Progress {
+    /// Generations created by the run
+    pub generations: usize,
+    /// Individuals evaluated by the run
+    pub evaluations: usize,
+    /// Wall time of the run
+    pub elapsed: Duration,
+    /// Best score of the run
+    pub best: f64,
+    /// Direction of the optimisation of the score
+    pub direction: Direction,
+    /// Generations since the last improvement of the best score
+    pub stagnation: usize,
+    /// True if the goal is reached
+    pub goal_reached: bool,
+    /// True if an Observer has asked to stop
+    pub stop_requested: bool,
+}
+
+/// Verify if the score a is better than the score b accordingly
+/// the direction, NaN is the worst score
+fn is_better(direction: Direction, a: f64, b: f64) -> bool {
+    direction.is_better(&OrdF64(a), &OrdF64(b))
+}
+
+impl Termination {
+    /// Return a Termination satisfied when both self and other are satisfied
+    pub fn and(self, other: Termination) -> Termination {
+        match self {
+            Termination::All(mut v) => {
+                v.push(other);
+                Termination::All(v)
+            }
+            t => Termination::All(vec![t, other]),
+        }
+    }
+
+    /// Return a Termination satisfied when self or other is satisfied
+    pub fn or(self, other: Termination) -> Termination {
+        match self {
+            Termination::Any(mut v) => {
+                v.push(other);
+                Termination::Any(v)
+            }
+            t => Termination::Any(vec![t, other]),
+        }
+    }
+
+    /// Get the criterion which is satisfied by the progress, if any.
+    /// For Any it is the first satisfied criterion, for All it is the whole All
+    pub fn check(&self, progress: &Progress) -> Option<Termination> {
+        let satisfied = match *self {
+            Termination::MaxGenerations(n) => progress.generations >= n,
+            Termination::MaxEvaluations(n) => progress.evaluations >= n,
+            Termination::WallClock(d) => progress.elapsed >= d,
+            Termination::TargetFitness(target) => {
+                !is_better(progress.direction, target, progress.best)
+            }
+            Termination::NoImprovement(n) => progress.stagnation >= n,
+            Termination::GoalReached => progress.goal_reached,
+            Termination::StopRequested => progress.stop_requested,
+            Termination::All(ref v) => v.iter().all(|t| t.check(progress).is_some()),
+            Termination::Any(ref v) => return v.iter().filter_map(|t| t.check(progress)).next(),
+        };
+        if satisfied { Some(self.clone()) } else { None }
+    }
+
+    /// Verify the criterion, the error contains every All or Any without criteria
+    pub fn validate(&self) -> Result<(), Error> {
+        check(self.problems())
+    }
+
+    /// Get the All and Any without criteria, at any depth
+    fn problems(&self) -> Vec<ConfigurationError> {
+        match *self {
+            Termination::All(ref v) |
+            Termination::Any(ref v) if v.is_empty() => vec![ConfigurationError::EmptyTermination],
+            Termination::All(ref v) |
+            Termination::Any(ref v) => v.iter().flat_map(|t| t.problems()).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Result of a run: the last Population (or Archipelago), the Statistics of each
+/// generation and the criterion which has ended the run
+#[derive(Clone)]
+pub struct Run<P> {
+    /// Last generation of the run
+    pub population: P,
+    /// Statistics of each generation, from the first one. The statistics
+    /// of the genomes are None unless run_with_statistics calculates them
+    pub history: Vec<Statistics>,
+    /// Criterion which has ended the run
+    pub termination: Termination,
+}
+
+/// Evolve pop until the termination is satisfied or an Observer asks to stop, goal is
+/// called on each generation to know if the goal is reached. The Observers are notified
+/// the first time the goal is reached. Only the statistics of the scores are calculated
+/// on each generation, none of the criteria needs the slower statistics of the genomes.
+/// Return an error if the termination is not valid
+pub fn run<T, S, P, G>(pop: P, termination: &Termination, goal: G) -> Result<Run<P>, Error>
+    where P: Evolution<T, S>,
+          G: Fn(&P) -> bool
+{
+    run_with_statistics(pop, termination, goal, |p: &P| p.get_statistics())
+}
+
+/// Evolve pop like run, the Statistics of each generation are calculated by statistics,
+/// for example with the statistics of the genomes. Return an error if the termination
+/// is not valid
+pub fn run_with_statistics<T, S, P, G, F>(pop: P,
+                                          termination: &Termination,
+                                          goal: G,
+                                          statistics: F)
+                                          -> Result<Run<P>, Error>
+    where P: Evolution<T, S>,
+          G: Fn(&P) -> bool,
+          F: Fn(&P) -> Statistics
+{
+    termination.validate()?;
+    let start = Instant::now();
+    let mut pop = pop;
+    let first = statistics(&pop);
+    let mut progress = Progress {
+        generations: 0,
+        evaluations: 0,
+        elapsed: Duration::new(0, 0),
+        best: first.best,
+        direction: pop.get_direction(),
+        stagnation: 0,
+        goal_reached: false,
+        stop_requested: false,
+    };
+    let mut history = vec![first];
+    loop {
+        if !progress.goal_reached && goal(&pop) {
+            progress.goal_reached = true;
+            progress.stop_requested |= pop.notify(&Event::GoalReached(history.last().unwrap()));
+        }
+        let fired = if progress.stop_requested {
+            Some(Termination::StopRequested)
+        } else {
+            termination.check(&progress)
+        };
+        if let Some(termination) = fired {
+            return Ok(Run {
+                population: pop,
+                history,
+                termination,
+            });
+        }
+        pop = pop.evolve();
+        let current = statistics(&pop);
+        progress.generations += 1;
+        progress.evaluations = current.evaluations - history[0].evaluations;
+        progress.elapsed = start.elapsed();
+        if is_better(progress.direction, current.best, progress.best) {
+            progress.best = current.best;
+            progress.stagnation = 0;
+        } else {
+            progress.stagnation += 1;
+        }
+        progress.stop_requested = pop.is_stop_requested();
+        history.push(current);
+    }
+}
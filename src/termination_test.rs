@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use genetic::*;
+use termination::*;
+use test_helpers::configuration_builder;
+use score::Direction;
+use error::{ConfigurationError, Error};
+
+#[allow(dead_code)]
+fn progress() -> Progress {
+    Progress {
+        generations: 10,
+        evaluations: 500,
+        elapsed: Duration::from_millis(20),
+        best: 7f64,
+        direction: Direction::Maximise,
+        stagnation: 3,
+        goal_reached: false,
+        stop_requested: false,
+    }
+}
+
+#[allow(dead_code)]
+fn population() -> Population<u8> {
+    let fitness = |i: &Individual<u8>| i.genes.iter().map(|g| (*g / 64) as i32).sum();
+    let c = configuration_builder(fitness, 16, 4, 3)
+        .mutation_rate(0.3f32)
+        .build()
+        .unwrap();
+    Population::new(c).unwrap()
+}
+
+#[test]
+fn criteria() {
+    let p = progress();
+    assert!(Termination::MaxGenerations(10).check(&p).is_some());
+    assert!(Termination::MaxGenerations(11).check(&p).is_none());
+    assert!(Termination::MaxEvaluations(400).check(&p).is_some());
+    assert!(Termination::WallClock(Duration::from_secs(1)).check(&p).is_none());
+    assert!(Termination::TargetFitness(7f64).check(&p).is_some());
+    assert!(Termination::TargetFitness(8f64).check(&p).is_none());
+    assert!(Termination::NoImprovement(3).check(&p).is_some());
+    assert!(Termination::GoalReached.check(&p).is_none());
+    let mut minimise = progress();
+    minimise.direction = Direction::Minimise;
+    assert!(Termination::TargetFitness(8f64).check(&minimise).is_some());
+    assert!(Termination::TargetFitness(6f64).check(&minimise).is_none());
+    let mut nan = progress();
+    nan.best = f64::NAN;
+    assert!(Termination::TargetFitness(1000f64).check(&nan).is_none());
+    nan.direction = Direction::Minimise;
+    assert!(Termination::TargetFitness(-1000f64).check(&nan).is_none());
+}
+
+#[test]
+fn combinations() {
+    let p = progress();
+    let any = Termination::GoalReached
+        .or(Termination::NoImprovement(2))
+        .or(Termination::MaxGenerations(5));
+    assert_eq!(any.check(&p), Some(Termination::NoImprovement(2)));
+    let all = Termination::MaxGenerations(5).and(Termination::GoalReached);
+    assert_eq!(all.check(&p), None);
+    let all = Termination::MaxGenerations(5).and(Termination::MaxEvaluations(100));
+    assert_eq!(all.check(&p), Some(all.clone()));
+}
+
+#[test]
+fn empty_criteria() {
+    assert!(Termination::MaxGenerations(5).or(Termination::GoalReached).validate().is_ok());
+    let nested = Termination::MaxGenerations(5).and(Termination::Any(vec![]));
+    for t in &[Termination::Any(vec![]), Termination::All(vec![]), nested] {
+        match t.validate() {
+            Err(Error::InvalidConfiguration(problems)) => {
+                assert_eq!(problems, vec![ConfigurationError::EmptyTermination])
+            }
+            _ => panic!("the termination has no criteria"),
+        }
+        assert!(run(population(), t, |_: &Population<u8>| false).is_err());
+    }
+}
+
+#[test]
+fn run_until_criterion() {
+    let result = run(population(), &Termination::MaxEvaluations(40), |_: &Population<u8>| false)
+        .unwrap();
+    assert_eq!(result.termination, Termination::MaxEvaluations(40));
+    assert_eq!(result.population.generation, 3);
+    assert_eq!(result.history.len(), 4);
+    assert!(result.history.iter().all(|s| s.diversity.is_none() && s.unique_genomes.is_none()));
+    let result = run(population(),
+                     &Termination::MaxGenerations(50).or(Termination::GoalReached),
+                     |p: &Population<u8>| p.get_fittest().unwrap().1 >= 10)
+        .unwrap();
+    assert!(result.termination == Termination::GoalReached ||
+            result.termination == Termination::MaxGenerations(50));
+    if result.termination == Termination::GoalReached {
+        assert!(result.population.get_fittest().unwrap().1 >= 10);
+    }
+}
@@ -0,0 +1,28 @@
+#![cfg(test)]
+
+use rand::Rand;
+
+use builder::PopulationConfigurationBuilder;
+use genetic::{Individual, PopulationConfiguration};
+use score::Score;
+use selection::Tournament;
+
+/// Get a builder of the configurations used by the tests, with population_size
+/// Individuals of genenumber genes, a tournament of 2, one elite, two threads and the seed
+pub fn configuration_builder<T, S, F>(fitness: F,
+                                      population_size: usize,
+                                      genenumber: usize,
+                                      seed: u64)
+                                      -> PopulationConfigurationBuilder<T, S>
+    where T: Clone + Rand + Send + Sync + PartialEq + 'static,
+          S: Score,
+          F: Fn(&Individual<T>) -> S + Send + Sync + 'static
+{
+    PopulationConfiguration::builder(fitness)
+        .population_size(population_size)
+        .genenumber(genenumber)
+        .selection(Tournament::new(2))
+        .elitism_size(1)
+        .threadpool_size(2)
+        .seed(seed)
+}